@@ -0,0 +1,53 @@
+/*
+ * PsychroLib (version 2.5.0) (https://github.com/psychrometrics/psychrolib).
+ * Copyright (c) 2018-2020 The PsychroLib Contributors. Licensed under the MIT License.
+*/
+
+// Build script compiling the C implementation of PsychroLib (src/c) so that the
+// Rust port can be tested against it. The object file is only linked into test
+// targets and the library itself never depends on it. When the C sources or a C
+// compiler are not available (e.g. when the crate is built on its own), nothing is
+// compiled and the differential tests are skipped.
+
+use std::env;
+use std::path::PathBuf;
+use std::process::Command;
+
+fn main() {
+    println!("cargo:rustc-check-cfg=cfg(psychrolib_c)");
+    println!("cargo:rerun-if-env-changed=CC");
+
+    let c_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap()).join("../../c");
+    let source = c_dir.join("psychrolib.c");
+    if !source.exists() {
+        return;
+    }
+    println!("cargo:rerun-if-changed={}", source.display());
+    println!(
+        "cargo:rerun-if-changed={}",
+        c_dir.join("psychrolib.h").display()
+    );
+
+    let object = PathBuf::from(env::var("OUT_DIR").unwrap()).join("psychrolib_c.o");
+    let compiler = env::var("CC").unwrap_or_else(|_| "cc".to_string());
+    let status = Command::new(&compiler)
+        .args(["-c", "-O2", "-fPIC", "-I"])
+        .arg(&c_dir)
+        .arg(&source)
+        .arg("-o")
+        .arg(&object)
+        .status();
+
+    match status {
+        Ok(status) if status.success() => {
+            println!("cargo:rustc-link-arg-tests={}", object.display());
+            println!("cargo:rustc-link-arg-tests=-lm");
+            println!("cargo:rustc-cfg=psychrolib_c");
+        }
+        _ => println!(
+            "cargo:warning=could not compile {} with {}, skipping tests against the C implementation",
+            source.display(),
+            compiler
+        ),
+    }
+}
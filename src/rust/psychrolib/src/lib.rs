@@ -5,142 +5,5099 @@
  * Licensed under the MIT License.
 */
 
+use std::cell::Cell;
+use std::error::Error;
+use std::fmt;
+
+pub mod quantity;
+pub mod solve;
+
+pub use quantity::{
+    BtuPerLb, Celsius, Fahrenheit, HumRatio, JoulePerKg, Pascal, Psi, Quantity, RelHum,
+};
+
+use quantity::IntoQuantity;
+
 /******************************************************************************************************
  * Global constants
  *****************************************************************************************************/
 
-const ZERO_FARENHEIT_AS_RANKINE: f64 = 459.67; // Zero degree Fahrenheit (°F) expressed as degree Rankine (°R).
-                                               // Reference: ASHRAE Handbook - Fundamentals (2017) ch. 39.
+pub const ZERO_FARENHEIT_AS_RANKINE: f64 = 459.67; // Zero degree Fahrenheit (°F) expressed as degree Rankine (°R).
+                                                   // Reference: ASHRAE Handbook - Fundamentals (2017) ch. 39.
+
+pub const ZERO_CELCIUS_AS_KELVIN: f64 = 273.15; // Zero degree Celsius (°C) expressed as Kelvin (K).
+                                                // Reference: ASHRAE Handbook - Fundamentals (2017) ch. 39.
+
+pub const R_DA_IP: f64 = 53.350; // Universal gas constant for dry air (IP version) in ft∙lbf/lb_da/R.
+                                 // Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1.
+
+pub const R_DA_SI: f64 = 287.042; // Universal gas constant for dry air (SI version) in J/kg_da/K.
+                                  // Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1.
+
+pub const MAX_ITER_COUNT: usize = 100; // Default max number of iterations before exiting while loop
+pub const MIN_HUM_RATIO: f64 = 1e-7; // Default minimum acceptable humidity ratio used/returned by any functions.
+                                     // Any value above 0 or below the MIN_HUM_RATIO will be reset to this value.
 
-const ZERO_CELCIUS_AS_KELVIN: f64 = 273.15; // Zero degree Celsius (°C) expressed as Kelvin (K).
-                                            // Reference: ASHRAE Handbook - Fundamentals (2017) ch. 39.
+pub const FREEZING_POINT_WATER_IP: f64 = 32.0; // Freezing point of water in Fahrenheit.
 
-const R_DA_IP: f64 = 53.350; // Universal gas constant for dry air (IP version) in ft∙lbf/lb_da/R.
-                             // Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1.
+pub const FREEZING_POINT_WATER_SI: f64 = 0.0; // Freezing point of water in Celsius.
 
-const R_DA_SI: f64 = 287.042; // Universal gas constant for dry air (SI version) in J/kg_da/K.
-                              // Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1.
+pub const TRIPLE_POINT_WATER_IP: f64 = 32.018; // Triple point of water in Fahrenheit.
 
-const MAX_ITER_COUNT: usize = 100; // Max number of iterations before exiting while loop
-const MIN_HUM_RATIO: f64 = 1e-7; // Minimum acceptable humidity ratio used/returned by any functions.
-                                 // Any value above 0 or below the MIN_HUM_RATIO will be reset to this value.
+pub const TRIPLE_POINT_WATER_SI: f64 = 0.01; // Triple point of water in Celsius.
 
-const FREEZING_POINT_WATER_IP: f64 = 32.0; // Freezing point of water in Fahrenheit.
+pub const PSI_AS_PASCAL: f64 = 6894.757293168; // One pound-force per square inch (Psi) expressed in Pascal (Pa).
 
-const FREEZING_POINT_WATER_SI: f64 = 0.0; // Freezing point of water in Celsius.
+pub const INHG_AS_PASCAL: f64 = 3386.389; // One inch of mercury (inHg, conventional) expressed in Pascal (Pa).
 
-const TRIPLE_POINT_WATER_IP: f64 = 32.018; // Triple point of water in Fahrenheit.
+pub const MMHG_AS_PASCAL: f64 = 133.322387415; // One millimetre of mercury (mmHg, conventional) expressed in Pascal (Pa).
 
-const TRIPLE_POINT_WATER_SI: f64 = 0.01; // Triple point of water in Celsius.
+pub const ATM_AS_PASCAL: f64 = 101325.0; // One standard atmosphere (atm) expressed in Pascal (Pa).
 
-const TOLERANCE_IP: f64 = 0.001 * 9.0 * 5.0; // Tolerance of temperature calculations in IP
+pub const BTU_PER_LB_AS_J_PER_KG: f64 = 2326.0; // One Btu lb⁻¹ (International Table Btu) expressed in J kg⁻¹.
+
+pub const FT3_PER_LB_AS_M3_PER_KG: f64 = 0.3048 * 0.3048 * 0.3048 / 0.45359237; // One ft³ lb⁻¹ expressed in m³ kg⁻¹.
+
+// Enthalpy of dry air at 0 °C in Btu lb⁻¹: offset between the reference states of the IP (dry air at 0 °F)
+// and SI (dry air at 0 °C) enthalpies. Both use liquid water at 0 °C as reference for water.
+// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 30.
+pub const ENTHALPY_REFERENCE_OFFSET_IP: f64 = 0.240 * FREEZING_POINT_WATER_IP;
+
+const TOLERANCE_IP: f64 = 0.001 * 9.0 / 5.0; // Tolerance of temperature calculations in IP
 
 const TOLERANCE_SI: f64 = 0.001; //Tolerance of temperature calculations in SI
 
+const WARM_START_STEP: f64 = 10.0; // First step of the wet-bulb bracketing from a seed, as a multiple of the tolerance
+
 /// UnitSystem describes the unit system (SI or IP) in use by psychrolib
-#[derive(PartialEq, Debug)]
+#[derive(PartialEq, Debug, Clone, Copy)]
 pub enum UnitSystem {
     IP,
     SI,
 }
 
-/// Psychrolib is the struct that represents the unit system and tolerance of an instance of the library
-pub struct Psychrolib {
-    units: UnitSystem,
-    tolerance: f64,
+/// Property identifies the input of a Psychrolib function that failed validation
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum Property {
+    TDryBulb,
+    TWetBulb,
+    TDewPoint,
+    RelHum,
+    HumRatio,
+    SpecificHum,
+    VapPres,
+}
+
+impl Property {
+    fn unit_label(self, units: UnitSystem) -> &'static str {
+        match (self, units) {
+            (Property::TDryBulb, UnitSystem::IP)
+            | (Property::TWetBulb, UnitSystem::IP)
+            | (Property::TDewPoint, UnitSystem::IP) => " °F",
+            (Property::TDryBulb, UnitSystem::SI)
+            | (Property::TWetBulb, UnitSystem::SI)
+            | (Property::TDewPoint, UnitSystem::SI) => " °C",
+            (Property::HumRatio, UnitSystem::IP) => " lb_H₂O lb_Air⁻¹",
+            (Property::HumRatio, UnitSystem::SI) => " kg_H₂O kg_Air⁻¹",
+            (Property::VapPres, UnitSystem::IP) => " Psi",
+            (Property::VapPres, UnitSystem::SI) => " Pa",
+            (Property::RelHum, _) | (Property::SpecificHum, _) => "",
+        }
+    }
+}
+
+impl fmt::Display for Property {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Property::TDryBulb => "Dry bulb temperature",
+            Property::TWetBulb => "Wet bulb temperature",
+            Property::TDewPoint => "Dew point temperature",
+            Property::RelHum => "Relative humidity",
+            Property::HumRatio => "Humidity ratio",
+            Property::SpecificHum => "Specific humidity",
+            Property::VapPres => "Partial pressure of water vapor",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// PsychroError describes why a Psychrolib function could not return a value
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum PsychroError {
+    /// An input lies outside the range of validity of the equations
+    OutOfRange {
+        property: Property,
+        value: f64,
+        min: f64,
+        max: f64,
+        units: UnitSystem,
+    },
+    /// A dew-point or wet-bulb temperature is above the dry-bulb temperature
+    AboveDryBulb {
+        property: Property,
+        value: f64,
+        t_dry_bulb: f64,
+        units: UnitSystem,
+    },
+    /// An iterative solver did not converge within the maximum number of iterations
+    ConvergenceNotReached {
+        function: &'static str,
+        iterations: usize,
+        units: UnitSystem,
+    },
+    /// A function given to a root finder of the solve module has the same sign at both bounds of the bracket
+    RootNotBracketed {
+        function: &'static str,
+        lower: f64,
+        upper: f64,
+    },
+    /// A pair of properties given to state_from does not fix a unique moist air state
+    DegeneratePair { pair: &'static str },
+    /// A Newton-Raphson or secant root finder of the solve module cannot take its next step from `x`, as the
+    /// derivative or secant slope there is 0 or not finite
+    ZeroDerivative {
+        function: &'static str,
+        x: f64,
+        iterations: usize,
+    },
+}
+
+impl fmt::Display for PsychroError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            PsychroError::OutOfRange {
+                property,
+                value,
+                min,
+                max,
+                units,
+            } => {
+                let label = property.unit_label(units);
+                // Specific humidity and the upper bound of unbounded inputs are open intervals
+                let closing = if property == Property::SpecificHum || max.is_infinite() {
+                    ")"
+                } else {
+                    "]"
+                };
+                write!(
+                    f,
+                    "{} of {}{} is outside range [{}, {}{}{}",
+                    property, value, label, min, max, closing, label
+                )
+            }
+            PsychroError::AboveDryBulb {
+                property,
+                value,
+                t_dry_bulb,
+                units,
+            } => {
+                let label = property.unit_label(units);
+                write!(
+                    f,
+                    "{} of {}{} is above dry bulb temperature of {}{}",
+                    property, value, label, t_dry_bulb, label
+                )
+            }
+            PsychroError::ConvergenceNotReached {
+                function,
+                iterations,
+                ..
+            } => write!(
+                f,
+                "Convergence not reached in {} after {} iterations",
+                function, iterations
+            ),
+            PsychroError::RootNotBracketed {
+                function,
+                lower,
+                upper,
+            } => write!(
+                f,
+                "Root not bracketed by [{}, {}] in {}",
+                lower, upper, function
+            ),
+            PsychroError::DegeneratePair { pair } => {
+                write!(f, "{} do not fix a unique moist air state", pair)
+            }
+            PsychroError::ZeroDerivative {
+                function,
+                x,
+                iterations,
+            } => write!(
+                f,
+                "Zero or non-finite derivative at {} in {} after {} iterations",
+                x, function, iterations
+            ),
+        }
+    }
+}
+
+impl Error for PsychroError {}
+
+/// SolverReport describes how an iterative solver reached its result, as returned by the
+/// *_with_diagnostics functions. Temperatures are in °F [IP] or °C [SI].
+///
+/// get_t_dry_bulb_from_moist_air_volume_and_hum_ratio is solved in closed form and has no report.
+#[derive(PartialEq, Debug, Clone, Default)]
+pub struct SolverReport {
+    /// Number of iterations used
+    pub iterations: usize,
+    /// Final residual: last Newton-Raphson step, or width of the final bisection bracket
+    pub residual: f64,
+    /// Whether the residual reached the tolerance. False when the solver stopped at the max number of
+    /// iterations, in which case the non-diagnostic function returns PsychroError::ConvergenceNotReached.
+    pub converged: bool,
+    /// Whether the solver hit a bound of its search domain: a Newton-Raphson estimate clamped to the range
+    /// of validity of the saturation vapor pressure, or a bisection ending at the dew-point or dry-bulb
+    /// temperature it started from
+    pub hit_bound: bool,
+    /// Estimate and bracket of the solution after each iteration
+    pub history: Vec<SolverStep>,
+}
+
+impl SolverReport {
+    // Records the estimate and bracket after an iteration.
+    fn record(&mut self, iterations: usize, estimate: f64, lower: f64, upper: f64) {
+        self.iterations = iterations;
+        self.history.push(SolverStep {
+            estimate,
+            lower,
+            upper,
+        });
+    }
+}
+
+/// SolverStep is one iteration of an iterative solver, see SolverReport
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct SolverStep {
+    /// Estimate of the solution
+    pub estimate: f64,
+    /// Lower bound of the bracket known to contain the solution
+    pub lower: f64,
+    /// Upper bound of the bracket known to contain the solution
+    pub upper: f64,
+}
+
+/// MoistAirState holds every psychrometric property of moist air at a given dry-bulb temperature and pressure,
+/// as returned by the calc_psychrometrics_* functions. Values are in the unit system of the Psychrolib that
+/// computed them, and typed after its Units.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct MoistAirState<U: Units = UnitSystem> {
+    /// Dry-bulb temperature in °F [IP] or °C [SI]
+    pub t_dry_bulb: U::Temperature,
+    /// Wet-bulb temperature in °F [IP] or °C [SI]
+    pub t_wet_bulb: U::Temperature,
+    /// Dew-point temperature in °F [IP] or °C [SI]
+    pub t_dew_point: U::Temperature,
+    /// Relative humidity in range [0, 1]
+    pub rel_hum: U::RelHum,
+    /// Humidity ratio in lb_H₂O lb_Air⁻¹ [IP] or kg_H₂O kg_Air⁻¹ [SI]
+    pub hum_ratio: U::HumRatio,
+    /// Partial pressure of water vapor in moist air in Psi [IP] or Pa [SI]
+    pub vap_pres: U::Pressure,
+    /// Moist air enthalpy in Btu lb⁻¹ [IP] or J kg⁻¹ [SI]
+    pub moist_air_enthalpy: U::Enthalpy,
+    /// Specific volume of moist air in ft³ lb⁻¹ [IP] or in m³ kg⁻¹ [SI]
+    pub moist_air_volume: f64,
+    /// Degree of saturation [unitless]
+    pub degree_of_saturation: f64,
+    /// Atmospheric pressure in Psi [IP] or Pa [SI]
+    pub pressure: U::Pressure,
+    /// Unit system of the values above
+    pub units: U,
+}
+
+impl<U: Units> MoistAirState<U> {
+    /// Returns the same moist air state with every property converted to another unit system.
+    ///
+    /// Temperatures, pressures and specific volumes are converted with the exact unit factors. Enthalpies are
+    /// also shifted between the reference states of ASHRAE's IP (dry air at 0 °F) and SI (dry air at 0 °C)
+    /// equations, so that a converted enthalpy matches the one computed directly in the other unit system.
+    /// Relative humidity, humidity ratio and degree of saturation are the same in both unit systems.
+    ///
+    /// # Example
+    ///     use psychrolib::{Psychrolib, UnitSystem};
+    ///
+    ///     let psych = Psychrolib::new(UnitSystem::SI);
+    ///     let state = psych.calc_psychrometrics_from_t_wet_bulb(40.0, 20.0, 101325.0).unwrap();
+    ///     let state_ip = state.to_units(UnitSystem::IP);
+    ///
+    ///     assert!((state_ip.t_dry_bulb - 104.0).abs() < 1e-9);
+    ///     assert!((state_ip.pressure - 14.696).abs() < 0.001);
+    ///     assert_eq!(state_ip.hum_ratio, state.hum_ratio);
+    pub fn to_units(&self, units: UnitSystem) -> MoistAirState {
+        self.convert(units)
+    }
+
+    /// Returns the same moist air state converted to the unit system of `units`, which may be chosen
+    /// at compile time (e.g. to turn a `MoistAirState<Si>` into a `MoistAirState<Ip>`). See `to_units`.
+    pub fn convert<V: Units>(&self, units: V) -> MoistAirState<V> {
+        let from = self.units.unit_system();
+        let to = units.unit_system();
+        let t = |value: U::Temperature| convert_temperature(value.into(), from, to).into_quantity();
+        let p = |value: U::Pressure| convert_pressure(value.into(), from, to).into_quantity();
+
+        MoistAirState {
+            t_dry_bulb: t(self.t_dry_bulb),
+            t_wet_bulb: t(self.t_wet_bulb),
+            t_dew_point: t(self.t_dew_point),
+            rel_hum: Into::<f64>::into(self.rel_hum).into_quantity(),
+            hum_ratio: Into::<f64>::into(self.hum_ratio).into_quantity(),
+            vap_pres: p(self.vap_pres),
+            moist_air_enthalpy: convert_enthalpy(self.moist_air_enthalpy.into(), from, to)
+                .into_quantity(),
+            moist_air_volume: convert_specific_volume(self.moist_air_volume, from, to),
+            degree_of_saturation: self.degree_of_saturation,
+            pressure: p(self.pressure),
+            units,
+        }
+    }
+}
+
+// Converts a temperature in °F [IP] or °C [SI] between unit systems.
+fn convert_temperature(value: f64, from: UnitSystem, to: UnitSystem) -> f64 {
+    match (from, to) {
+        (UnitSystem::SI, UnitSystem::IP) => Fahrenheit::from(Celsius(value)).0,
+        (UnitSystem::IP, UnitSystem::SI) => Celsius::from(Fahrenheit(value)).0,
+        _ => value,
+    }
+}
+
+// Converts a pressure in Psi [IP] or Pa [SI] between unit systems.
+fn convert_pressure(value: f64, from: UnitSystem, to: UnitSystem) -> f64 {
+    match (from, to) {
+        (UnitSystem::SI, UnitSystem::IP) => Psi::from(Pascal(value)).0,
+        (UnitSystem::IP, UnitSystem::SI) => Pascal::from(Psi(value)).0,
+        _ => value,
+    }
+}
+
+// Converts a moist air enthalpy in Btu lb⁻¹ [IP] or J kg⁻¹ [SI] between unit systems,
+// including the shift between the reference states of the IP and SI equations.
+fn convert_enthalpy(value: f64, from: UnitSystem, to: UnitSystem) -> f64 {
+    match (from, to) {
+        (UnitSystem::SI, UnitSystem::IP) => BtuPerLb::from(JoulePerKg(value)).0,
+        (UnitSystem::IP, UnitSystem::SI) => JoulePerKg::from(BtuPerLb(value)).0,
+        _ => value,
+    }
+}
+
+// Converts a specific volume in ft³ lb⁻¹ [IP] or m³ kg⁻¹ [SI] between unit systems.
+fn convert_specific_volume(value: f64, from: UnitSystem, to: UnitSystem) -> f64 {
+    match (from, to) {
+        (UnitSystem::SI, UnitSystem::IP) => value / FT3_PER_LB_AS_M3_PER_KG,
+        (UnitSystem::IP, UnitSystem::SI) => value * FT3_PER_LB_AS_M3_PER_KG,
+        _ => value,
+    }
+}
+
+/// Units resolves the unit system of a Psychrolib, together with the tolerance and bounds that depend on it.
+///
+/// `UnitSystem` implements Units to select the unit system at runtime, with all quantities as `f64`. The zero-sized
+/// `Si` and `Ip` select it at compile time instead, so that a `Psychrolib<Si>` and a `Psychrolib<Ip>` are distinct
+/// types accepting and returning the newtypes of the `quantity` module.
+///
+/// # Example
+///     use psychrolib::{Celsius, Pascal, Psychrolib, RelHum, Si};
+///
+///     let psych = Psychrolib::new(Si);
+///     let t_dew_point = psych.get_t_dew_point_from_rel_hum(Celsius(25.0), RelHum(0.8)).unwrap();
+///     let hum_ratio = psych.get_hum_ratio_from_t_dew_point(t_dew_point, Pascal(101325.0)).unwrap();
+///
+///     assert!((t_dew_point.0 - 21.309397163661785).abs() < 0.001);
+///     assert!((hum_ratio.0 - 0.0159).abs() < 0.0001);
+///
+/// Passing a quantity of the wrong kind, or in the other unit system, does not compile:
+///
+/// ```compile_fail
+/// use psychrolib::{Fahrenheit, Psychrolib, RelHum, Si};
+///
+/// let psych = Psychrolib::new(Si);
+/// psych.get_t_dew_point_from_rel_hum(Fahrenheit(77.0), RelHum(0.8));
+/// ```
+///
+/// Neither does passing bare numbers, e.g. a relative humidity as a percentage:
+///
+/// ```compile_fail
+/// use psychrolib::{Psychrolib, Si};
+///
+/// let psych = Psychrolib::new(Si);
+/// psych.get_t_dew_point_from_rel_hum(25.0.into(), 80.0.into());
+/// ```
+pub trait Units: Copy {
+    /// Type of temperatures in °F [IP] or °C [SI]
+    type Temperature: Quantity;
+    /// Type of pressures in Psi [IP] or Pa [SI]
+    type Pressure: Quantity;
+    /// Type of enthalpies in Btu lb⁻¹ [IP] or J kg⁻¹ [SI]
+    type Enthalpy: Quantity;
+    /// Type of humidity ratios in lb_H₂O lb_Air⁻¹ [IP] or kg_H₂O kg_Air⁻¹ [SI]
+    type HumRatio: Quantity;
+    /// Type of relative humidities in range [0, 1]
+    type RelHum: Quantity;
+
+    /// Returns the unit system (SI or IP)
+    fn unit_system(&self) -> UnitSystem;
+
+    /// Returns the tolerance of temperature calculations in °F [IP] or °C [SI]
+    fn tolerance(&self) -> f64 {
+        match self.unit_system() {
+            UnitSystem::IP => TOLERANCE_IP,
+            UnitSystem::SI => TOLERANCE_SI,
+        }
+    }
+
+    /// Returns the range of dry-bulb temperatures in °F [IP] or °C [SI] over which the saturation
+    /// vapor pressure equations are valid
+    fn t_dry_bulb_bounds(&self) -> (f64, f64) {
+        match self.unit_system() {
+            UnitSystem::IP => (-148.0, 392.0),
+            UnitSystem::SI => (-100.0, 200.0),
+        }
+    }
+}
+
+impl Units for UnitSystem {
+    type Temperature = f64;
+    type Pressure = f64;
+    type Enthalpy = f64;
+    type HumRatio = f64;
+    type RelHum = f64;
+
+    fn unit_system(&self) -> UnitSystem {
+        *self
+    }
+}
+
+/// Si selects the SI unit system at compile time
+#[derive(PartialEq, Debug, Clone, Copy, Default)]
+pub struct Si;
+
+impl Units for Si {
+    type Temperature = Celsius;
+    type Pressure = Pascal;
+    type Enthalpy = JoulePerKg;
+    type HumRatio = HumRatio;
+    type RelHum = RelHum;
+
+    fn unit_system(&self) -> UnitSystem {
+        UnitSystem::SI
+    }
+}
+
+/// Ip selects the IP unit system at compile time
+#[derive(PartialEq, Debug, Clone, Copy, Default)]
+pub struct Ip;
+
+impl Units for Ip {
+    type Temperature = Fahrenheit;
+    type Pressure = Psi;
+    type Enthalpy = BtuPerLb;
+    type HumRatio = HumRatio;
+    type RelHum = RelHum;
+
+    fn unit_system(&self) -> UnitSystem {
+        UnitSystem::IP
+    }
+}
+
+/// PsychrolibConfig holds the settings of the iterative solvers and validity checks of a Psychrolib.
+///
+/// The defaults reproduce the reference implementations of PsychroLib. A larger tolerance or fewer iterations
+/// trade accuracy for speed, e.g. in fast control loops; a smaller tolerance tightens results for lab work.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct PsychrolibConfig {
+    /// Tolerance of temperature calculations in °F [IP] or °C [SI], or None for the default of the unit system
+    pub tolerance: Option<f64>,
+    /// Max number of iterations of the iterative solvers
+    pub max_iter_count: usize,
+    /// Minimum humidity ratio used/returned by any functions in lb_H₂O lb_Air⁻¹ [IP] or kg_H₂O kg_Air⁻¹ [SI]
+    pub min_hum_ratio: f64,
+    /// Whether humidity ratios below min_hum_ratio are raised to it. When disabled, humidity ratios are used
+    /// and returned as computed, down to 0, and a dry air input may leave the dew point out of range.
+    pub clamp_hum_ratio: bool,
+    /// What to do with inputs outside the range of validity of the equations
+    pub out_of_range: OutOfRangePolicy,
+    /// Root finder of the wet-bulb temperature
+    pub wet_bulb_solver: WetBulbSolver,
+    /// Formulation of the saturation vapor pressure
+    pub saturation_model: SaturationModel,
+    /// Phase of water over which saturation is taken below the triple point of water
+    pub saturation_phase: SaturationPhase,
+    /// Whether saturation vapor pressures in moist air include the enhancement factor of real-gas moist air,
+    /// rather than following ideal-gas mixing
+    pub enhancement_factor: bool,
+    /// Function called with the error of each input clamped under OutOfRangePolicy::Clamp
+    pub warning_handler: WarningHandler,
+}
+
+impl Default for PsychrolibConfig {
+    fn default() -> PsychrolibConfig {
+        PsychrolibConfig {
+            tolerance: None,
+            max_iter_count: MAX_ITER_COUNT,
+            min_hum_ratio: MIN_HUM_RATIO,
+            clamp_hum_ratio: true,
+            out_of_range: OutOfRangePolicy::Strict,
+            wet_bulb_solver: WetBulbSolver::Bisection,
+            saturation_model: SaturationModel::Ashrae,
+            saturation_phase: SaturationPhase::Automatic,
+            enhancement_factor: false,
+            warning_handler: WarningHandler(ignore_warning),
+        }
+    }
+}
+
+/// WarningHandler wraps the function called with the warnings of a Psychrolib. Handlers compare equal when
+/// they wrap the same function.
+#[derive(Clone, Copy)]
+pub struct WarningHandler(pub fn(&PsychroError));
+
+impl PartialEq for WarningHandler {
+    fn eq(&self, other: &WarningHandler) -> bool {
+        std::ptr::fn_addr_eq(self.0, other.0)
+    }
+}
+
+impl fmt::Debug for WarningHandler {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "WarningHandler({:p})", self.0)
+    }
+}
+
+// Default warning handler, discarding the warning.
+fn ignore_warning(_: &PsychroError) {}
+
+/// WetBulbSolver selects the root finder of the wet-bulb temperature, which searches the interval between the
+/// dew-point and dry-bulb temperatures. Both keep the solution bracketed and return it within the tolerance.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum WetBulbSolver {
+    /// Bisection, as the reference implementations do
+    Bisection,
+    /// Brent's method, combining bisection with secant and inverse quadratic interpolation steps. It usually
+    /// needs less than half the iterations of bisection, and fewer still at tight tolerances.
+    ///
+    /// Reference: Brent RP, Algorithms for minimization without derivatives, Prentice-Hall 1973, ch. 4
+    Brent,
+}
+
+/// SaturationModel selects the formulation of the saturation vapor pressure, over liquid water above the triple
+/// point of water and over ice below it. Every other property depending on saturation follows, e.g. relative
+/// humidities and the dew-point and wet-bulb solvers.
+///
+/// All formulations are evaluated over the range of validity of the ASHRAE formulae, even where their own
+/// range is narrower.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum SaturationModel {
+    /// Hyland and Wexler formulae, as the reference implementations do
+    ///
+    /// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 5 & 6
+    Ashrae,
+    /// IAPWS saturation vapor pressure equation over water, consistent with IAPWS-95, and IAPWS sublimation
+    /// pressure equation over ice
+    ///
+    /// Reference: Wagner W, Pruss A, J. Phys. Chem. Ref. Data 22, 783 (1993) eqn 2.5;
+    /// Wagner W, Riethmann T, Feistel R, Harvey AH, J. Phys. Chem. Ref. Data 40, 043103 (2011) eqn 6
+    WagnerPruss,
+    /// Magnus formulae with the coefficients of the WMO
+    ///
+    /// Reference: WMO Guide to Meteorological Instruments and Methods of Observation (WMO-No. 8, 2018),
+    /// vol. I annex 4.B
+    MagnusTetens,
+    /// Buck formulae ew1 and ei1
+    ///
+    /// Reference: Buck AL, J. Appl. Meteorol. 20, 1527 (1981)
+    Buck1981,
+    /// Buck formulae as updated in the manual of the Buck Research CR-1A hygrometer
+    ///
+    /// Reference: Buck Research Instruments, Model CR-1A Hygrometer Operating Manual (1996)
+    Buck1996,
+    /// Goff-Gratch formulae, formerly recommended by the WMO
+    ///
+    /// Reference: WMO Technical Regulations (WMO-No. 49, 1988), vol. I appendix A
+    GoffGratch,
+    /// Sonntag formulae
+    ///
+    /// Reference: Sonntag D, Zeitschrift für Meteorologie 40, 340 (1990)
+    Sonntag,
+}
+
+/// SaturationPhase selects the phase of water over which saturation is taken, in the saturation vapor pressure
+/// and every property depending on it, e.g. relative humidity and dew-point temperature.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum SaturationPhase {
+    /// Over liquid water above the triple point of water and over ice below it, as the reference
+    /// implementations do. Dew-point temperatures below the triple point are then frost points.
+    Automatic,
+    /// Over liquid water, supercooled below the triple point, as relative humidities are conventionally
+    /// reported in meteorology. Dew-point temperatures are dew points at all temperatures. The formulae
+    /// over liquid water are extrapolated below their range of validity, and the wet bulb is taken as
+    /// supercooled water too.
+    Water,
+    /// Over ice, e.g. to compute frost points above the triple point of water. The formulae over ice are
+    /// extrapolated above their range of validity, and the wet bulb is taken as an ice bulb at all
+    /// temperatures, consistently with the saturation humidity ratio over ice at the wet bulb.
+    Ice,
+}
+
+/// OutOfRangePolicy selects what every Psychrolib function does with an input outside the range of validity
+/// of the equations, i.e. any input that would otherwise give a PsychroError::OutOfRange or
+/// PsychroError::AboveDryBulb. NaN inputs are out of range.
+///
+/// The raising of humidity ratios to the minimum humidity ratio is set separately, by
+/// PsychrolibConfig::clamp_hum_ratio.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum OutOfRangePolicy {
+    /// Return the error, as the reference implementations do
+    Strict,
+    /// Replace the input with the nearest value in range, passing the error to
+    /// PsychrolibConfig::warning_handler. NaN inputs give NaN.
+    Clamp,
+    /// Return NaN for every value depending on the input, e.g. for batch analytics over noisy data
+    Nan,
 }
 
-impl Psychrolib {
+/// Psychrolib is the struct that represents the unit system and solver settings of an instance of the library.
+///
+/// The unit system is either chosen at runtime (`Psychrolib<UnitSystem>`, the default) or fixed by the
+/// type (`Psychrolib<Si>` or `Psychrolib<Ip>`). The solver settings default to PsychrolibConfig::default()
+/// and are changed with the with_* builder methods.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct Psychrolib<U: Units = UnitSystem> {
+    units: U,
+    config: PsychrolibConfig,
+}
+
+impl<U: Units> Psychrolib<U> {
     /// Instantiates Psychrolib struct with unit system to use (SI or IP) and associated tolerance
     ///
     /// # Example
-    ///     use crate::psychrolib::UnitSystem;
-    ///     use crate::psychrolib::Psychrolib;
+    ///     use psychrolib::{Ip, Psychrolib, UnitSystem};
     ///
-    ///     let unit_system = psychrolib::UnitSystem::SI;
+    ///     let unit_system = UnitSystem::SI;
     ///     let psych = Psychrolib::new(unit_system);
-    pub fn new(units: UnitSystem) -> Psychrolib {
-        let tolerance;
-
-        if units == UnitSystem::IP {
-            tolerance = TOLERANCE_IP;
-        } else {
-            tolerance = TOLERANCE_SI;
+    ///
+    ///     // Unit system fixed at compile time
+    ///     let psych_ip = Psychrolib::new(Ip);
+    pub fn new(units: U) -> Psychrolib<U> {
+        Psychrolib {
+            units,
+            config: PsychrolibConfig::default(),
         }
+    }
+
+    /// Returns the Psychrolib with all its solver settings replaced by `config`
+    pub fn with_config(self, config: PsychrolibConfig) -> Psychrolib<U> {
+        Psychrolib { config, ..self }
+    }
+
+    /// Returns the Psychrolib with the tolerance of temperature calculations set to `tolerance`
+    /// in °F [IP] or °C [SI]
+    ///
+    /// # Example
+    ///     use psychrolib::{Psychrolib, UnitSystem};
+    ///
+    ///     let psych = Psychrolib::new(UnitSystem::SI)
+    ///         .with_tolerance(0.05)
+    ///         .with_max_iter_count(20);
+    ///     let t_wet_bulb = psych.get_t_wet_bulb_from_rel_hum(25.0, 0.5, 101325.0).unwrap();
+    ///
+    ///     assert!((t_wet_bulb - 17.9).abs() < 0.1);
+    ///
+    /// # Panics
+    ///
+    /// Panics if the tolerance is not strictly positive.
+    pub fn with_tolerance(mut self, tolerance: f64) -> Psychrolib<U> {
+        assert!(tolerance > 0.0, "tolerance must be strictly positive");
+        self.config.tolerance = Some(tolerance);
+        self
+    }
 
-        Psychrolib { units, tolerance }
+    /// Returns the Psychrolib with the max number of iterations of the iterative solvers set to `max_iter_count`
+    ///
+    /// # Panics
+    ///
+    /// Panics if the max number of iterations is 0.
+    pub fn with_max_iter_count(mut self, max_iter_count: usize) -> Psychrolib<U> {
+        assert!(max_iter_count > 0, "max_iter_count must be at least 1");
+        self.config.max_iter_count = max_iter_count;
+        self
+    }
+
+    /// Returns the Psychrolib with the minimum humidity ratio set to `min_hum_ratio`
+    /// in lb_H₂O lb_Air⁻¹ [IP] or kg_H₂O kg_Air⁻¹ [SI]
+    ///
+    /// # Panics
+    ///
+    /// Panics if the minimum humidity ratio is not strictly positive.
+    pub fn with_min_hum_ratio(mut self, min_hum_ratio: f64) -> Psychrolib<U> {
+        assert!(
+            min_hum_ratio > 0.0,
+            "min_hum_ratio must be strictly positive"
+        );
+        self.config.min_hum_ratio = min_hum_ratio;
+        self
+    }
+
+    /// Returns the Psychrolib raising humidity ratios below the minimum humidity ratio to it or not,
+    /// depending on `clamp_hum_ratio`
+    pub fn with_hum_ratio_clamping(mut self, clamp_hum_ratio: bool) -> Psychrolib<U> {
+        self.config.clamp_hum_ratio = clamp_hum_ratio;
+        self
+    }
+
+    /// Returns the Psychrolib applying `policy` to inputs outside the range of validity of the equations
+    ///
+    /// # Example
+    ///     use psychrolib::{OutOfRangePolicy, Psychrolib, UnitSystem};
+    ///
+    ///     let psych = Psychrolib::new(UnitSystem::SI);
+    ///     assert!(psych.get_hum_ratio_from_rel_hum(25.0, 1.2, 101325.0).is_err());
+    ///
+    ///     let psych = psych.with_out_of_range_policy(OutOfRangePolicy::Nan);
+    ///     assert!(psych.get_hum_ratio_from_rel_hum(25.0, 1.2, 101325.0).unwrap().is_nan());
+    pub fn with_out_of_range_policy(mut self, policy: OutOfRangePolicy) -> Psychrolib<U> {
+        self.config.out_of_range = policy;
+        self
+    }
+
+    /// Returns the Psychrolib calling `handler` with the error of each input clamped under
+    /// OutOfRangePolicy::Clamp, e.g. to log or count the clamped inputs. Warnings are discarded by default.
+    ///
+    /// # Example
+    ///     use psychrolib::{OutOfRangePolicy, PsychroError, Psychrolib, UnitSystem};
+    ///
+    ///     fn log_warning(error: &PsychroError) {
+    ///         eprintln!("psychrolib warning: {}", error);
+    ///     }
+    ///
+    ///     let psych = Psychrolib::new(UnitSystem::SI)
+    ///         .with_out_of_range_policy(OutOfRangePolicy::Clamp)
+    ///         .with_warning_handler(log_warning);
+    ///     assert!(psych.get_hum_ratio_from_rel_hum(25.0, 1.2, 101325.0).is_ok());
+    pub fn with_warning_handler(mut self, handler: fn(&PsychroError)) -> Psychrolib<U> {
+        self.config.warning_handler = WarningHandler(handler);
+        self
+    }
+
+    /// Returns the Psychrolib solving for wet-bulb temperatures with `solver`
+    ///
+    /// # Example
+    ///     use psychrolib::{Psychrolib, UnitSystem, WetBulbSolver};
+    ///
+    ///     let psych = Psychrolib::new(UnitSystem::SI).with_wet_bulb_solver(WetBulbSolver::Brent);
+    ///     let (t_wet_bulb, report) = psych
+    ///         .get_t_wet_bulb_from_hum_ratio_with_diagnostics(25.0, 0.01, 101325.0)
+    ///         .unwrap();
+    ///
+    ///     assert!((t_wet_bulb - 17.9).abs() < 0.1);
+    ///     assert!(report.iterations < 10);
+    pub fn with_wet_bulb_solver(mut self, solver: WetBulbSolver) -> Psychrolib<U> {
+        self.config.wet_bulb_solver = solver;
+        self
+    }
+
+    /// Returns the Psychrolib computing saturation vapor pressures with `model`
+    ///
+    /// # Example
+    ///     use psychrolib::{Psychrolib, SaturationModel, UnitSystem};
+    ///
+    ///     let psych = Psychrolib::new(UnitSystem::SI).with_saturation_model(SaturationModel::MagnusTetens);
+    ///     let sat_vap_pres = psych.get_sat_vap_pres(20.0).unwrap();
+    ///
+    ///     assert!((sat_vap_pres - 2332.6).abs() < 0.1);
+    pub fn with_saturation_model(mut self, model: SaturationModel) -> Psychrolib<U> {
+        self.config.saturation_model = model;
+        self
+    }
+
+    /// Returns the Psychrolib taking saturation over the phase of water `phase`
+    ///
+    /// # Example
+    ///     use psychrolib::{Psychrolib, SaturationPhase, UnitSystem};
+    ///
+    ///     let psych = Psychrolib::new(UnitSystem::SI);
+    ///     let over_water = psych.with_saturation_phase(SaturationPhase::Water);
+    ///
+    ///     // At -20 °C, ice saturates at a lower vapor pressure than supercooled water
+    ///     let rel_hum = psych.get_rel_hum_from_vap_pres(-20.0, 100.0).unwrap();
+    ///     let rel_hum_over_water = over_water.get_rel_hum_from_vap_pres(-20.0, 100.0).unwrap();
+    ///     assert!((rel_hum - 0.97).abs() < 0.01);
+    ///     assert!((rel_hum_over_water - 0.79).abs() < 0.01);
+    ///
+    ///     // Frost point and dew point of the same vapor pressure
+    ///     let t_frost_point = psych.get_t_dew_point_from_vap_pres(-10.0, 100.0).unwrap();
+    ///     let t_dew_point = over_water.get_t_dew_point_from_vap_pres(-10.0, 100.0).unwrap();
+    ///     assert!(t_dew_point < t_frost_point);
+    pub fn with_saturation_phase(mut self, phase: SaturationPhase) -> Psychrolib<U> {
+        self.config.saturation_phase = phase;
+        self
+    }
+
+    /// Returns the Psychrolib applying the enhancement factor of real-gas moist air (see get_enhancement_factor)
+    /// to saturation vapor pressures in moist air if `enabled`. This matters for compressed air and other
+    /// pressures well above atmospheric, where ideal-gas mixing underestimates the water content at saturation.
+    ///
+    /// The factor applies to every function given both a temperature and the pressure, e.g. conversions between
+    /// humidity ratio and relative humidity or dew-point temperature. Functions without the pressure, e.g.
+    /// get_rel_hum_from_vap_pres, are unchanged.
+    ///
+    /// # Example
+    ///     use psychrolib::{Psychrolib, UnitSystem};
+    ///
+    ///     let psych = Psychrolib::new(UnitSystem::SI);
+    ///     let real_gas = psych.with_enhancement_factor(true);
+    ///
+    ///     let sat_hum_ratio = psych.get_sat_hum_ratio(20.0, 800000.0).unwrap();
+    ///     let real_gas_sat_hum_ratio = real_gas.get_sat_hum_ratio(20.0, 800000.0).unwrap();
+    ///     assert!(real_gas_sat_hum_ratio / sat_hum_ratio > 1.02);
+    pub fn with_enhancement_factor(mut self, enabled: bool) -> Psychrolib<U> {
+        self.config.enhancement_factor = enabled;
+        self
+    }
+
+    /// Returns the solver settings of the Psychrolib
+    pub fn get_config(&self) -> PsychrolibConfig {
+        self.config
     }
 
     /// Returns the unit system in use by the Psychrolib
     ///
     /// # Example
-    ///     use crate::psychrolib::UnitSystem;
-    ///     use crate::psychrolib::Psychrolib;
+    ///     use psychrolib::{Psychrolib, Si, UnitSystem};
     ///
-    ///     let unit_system = psychrolib::UnitSystem::IP;
+    ///     let unit_system = UnitSystem::IP;
     ///     let psych = Psychrolib::new(unit_system);
     ///
-    ///     assert_eq!(psych.get_units(), &psychrolib::UnitSystem::IP)
+    ///     assert_eq!(psych.get_units(), UnitSystem::IP);
+    ///     assert_eq!(Psychrolib::new(Si).get_units(), UnitSystem::SI);
     ///
-    pub fn GetUnitSystem(&self) -> &UnitSystem {
-        &self.units
+    pub fn get_units(&self) -> UnitSystem {
+        self.units.unit_system()
     }
 
-    /// Sets the unit system to a Psychrolib already in use
+    /// Returns the tolerance of temperature calculations in °F [IP] or °C [SI]
+    ///
+    /// # Example
+    ///     use psychrolib::{Psychrolib, UnitSystem};
+    ///
+    ///     let psych = Psychrolib::new(UnitSystem::SI);
+    ///
+    ///     assert_eq!(psych.get_tolerance(), 0.001)
+    ///
+    pub fn get_tolerance(&self) -> f64 {
+        self.config
+            .tolerance
+            .unwrap_or_else(|| self.units.tolerance())
+    }
+
+    fn is_ip(&self) -> bool {
+        self.units.unit_system() == UnitSystem::IP
+    }
+}
+
+impl Psychrolib<UnitSystem> {
+    /// Sets the unit system to a Psychrolib already in use. The tolerance follows the new unit system:
+    /// a tolerance set with with_tolerance is converted to it, the default one is that of the new unit system.
+    ///
+    /// Only available when the unit system is chosen at runtime.
     ///
     /// # Example:
-    ///     use crate::psychrolib::UnitSystem;
-    ///     use crate::psychrolib::Psychrolib;
+    ///     use psychrolib::{Psychrolib, UnitSystem};
+    ///
+    ///     let mut psych = Psychrolib::new(UnitSystem::IP);
     ///
-    ///     let mut psych = Psychrolib::new(psychrolib::UnitSystem::IP);
-    /// 
-    ///     assert_eq!(psych.get_units(), &psychrolib::UnitSystem::IP);
+    ///     assert_eq!(psych.get_units(), UnitSystem::IP);
     ///
-    ///     psych.set_units(psychrolib::UnitSystem::SI);
-    /// 
-    ///     assert_eq!(psych.get_units(), &psychrolib::UnitSystem::SI);
+    ///     psych.set_units(UnitSystem::SI);
+    ///
+    ///     assert_eq!(psych.get_units(), UnitSystem::SI);
+    ///     assert_eq!(psych.get_tolerance(), 0.001);
     pub fn set_units(&mut self, unit_system: UnitSystem) {
+        if let Some(tolerance) = self.config.tolerance {
+            self.config.tolerance = Some(match (self.units, unit_system) {
+                (UnitSystem::SI, UnitSystem::IP) => tolerance * 9.0 / 5.0,
+                (UnitSystem::IP, UnitSystem::SI) => tolerance * 5.0 / 9.0,
+                _ => tolerance,
+            });
+        }
         self.units = unit_system;
     }
 }
 
+impl<U: Units + Default> Default for Psychrolib<U> {
+    fn default() -> Psychrolib<U> {
+        Psychrolib::new(U::default())
+    }
+}
+
 /******************************************************************************************************
  * Helper functions
  *****************************************************************************************************/
 
- fn get_t_rankine_from_t_fahrenheit(t_fahrenheit: f64) -> f64 {
+impl<U: Units> Psychrolib<U> {
+    fn out_of_range(&self, property: Property, value: f64, min: f64, max: f64) -> PsychroError {
+        PsychroError::OutOfRange {
+            property,
+            value,
+            min,
+            max,
+            units: self.units.unit_system(),
+        }
+    }
 
-    t_fahrenheit
- }
+    // Applies the out-of-range policy to an input that failed validation with `error`, and returns
+    // the value to compute with: the error, `clamped` (NaN for a NaN input) or NaN.
+    // Every validity check goes through this function.
+    fn on_out_of_range(
+        &self,
+        error: PsychroError,
+        value: f64,
+        clamped: f64,
+    ) -> Result<f64, PsychroError> {
+        match self.config.out_of_range {
+            OutOfRangePolicy::Strict => Err(error),
+            OutOfRangePolicy::Clamp if value.is_nan() => Ok(f64::NAN),
+            OutOfRangePolicy::Clamp => {
+                (self.config.warning_handler.0)(&error);
+                Ok(clamped)
+            }
+            OutOfRangePolicy::Nan => Ok(f64::NAN),
+        }
+    }
 
+    fn convergence_not_reached(&self, function: &'static str) -> PsychroError {
+        PsychroError::ConvergenceNotReached {
+            function,
+            iterations: self.config.max_iter_count,
+            units: self.units.unit_system(),
+        }
+    }
 
+    // Checks that an input is in range [min, max] and returns the value to compute with.
+    fn check_range(
+        &self,
+        property: Property,
+        value: f64,
+        min: f64,
+        max: f64,
+    ) -> Result<f64, PsychroError> {
+        if (min..=max).contains(&value) {
+            return Ok(value);
+        }
 
+        let error = self.out_of_range(property, value, min, max);
+        self.on_out_of_range(error, value, value.clamp(min, max))
+    }
 
+    // Checks that a relative humidity is in range [0, 1] and returns its value.
+    fn check_rel_hum(&self, rel_hum: U::RelHum) -> Result<f64, PsychroError> {
+        self.check_range(Property::RelHum, rel_hum.into(), 0.0, 1.0)
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    // Checks that a dew-point or wet-bulb temperature is not above the dry-bulb temperature and returns it.
+    fn check_below_t_dry_bulb(
+        &self,
+        property: Property,
+        value: U::Temperature,
+        t_dry_bulb: U::Temperature,
+    ) -> Result<U::Temperature, PsychroError> {
+        if value <= t_dry_bulb || value.into().is_nan() || t_dry_bulb.into().is_nan() {
+            // NaN temperatures are left to the checks of the functions they are passed to
+            return Ok(value);
+        }
 
-    #[test]
-    fn set_units_ip() {
-        let psych = Psychrolib::new(UnitSystem::IP);
+        let error = PsychroError::AboveDryBulb {
+            property,
+            value: value.into(),
+            t_dry_bulb: t_dry_bulb.into(),
+            units: self.units.unit_system(),
+        };
+        Ok(self
+            .on_out_of_range(error, value.into(), t_dry_bulb.into())?
+            .into_quantity())
+    }
 
-        assert_eq!(psych.units, UnitSystem::IP);
-        assert_eq!(psych.tolerance, TOLERANCE_IP);
+    // Checks that a partial pressure of water vapor is not negative and returns its value.
+    fn check_vap_pres(&self, vap_pres: U::Pressure) -> Result<f64, PsychroError> {
+        self.check_range(Property::VapPres, vap_pres.into(), 0.0, f64::INFINITY)
     }
 
-    #[test]
-    fn get_unit_test() {
-        let psych = Psychrolib::new(UnitSystem::IP);
+    // Checks that a humidity ratio is not negative and raises it to the minimum humidity ratio if needed.
+    // Every function taking a humidity ratio as input goes through this check.
+    fn bounded_hum_ratio(&self, hum_ratio: U::HumRatio) -> Result<f64, PsychroError> {
+        let hum_ratio =
+            self.check_range(Property::HumRatio, hum_ratio.into(), 0.0, f64::INFINITY)?;
 
-        assert_eq!(psych.get_units(), &UnitSystem::IP);
+        Ok(self.clamp_hum_ratio(hum_ratio))
     }
 
-    #[test]
-    fn get_t_rankine_from_t_fahrenheit() {
-        let psych = Psychrolib::new(UnitSystem::IP);
+    // Raises a humidity ratio to the minimum humidity ratio, unless clamping is disabled. NaN stays NaN.
+    fn clamp_hum_ratio(&self, hum_ratio: f64) -> f64 {
+        if self.config.clamp_hum_ratio && hum_ratio < self.config.min_hum_ratio {
+            self.config.min_hum_ratio
+        } else {
+            hum_ratio
+        }
+    }
+
+    // Returns whether saturation at a temperature in °F [IP] or °C [SI] is over ice rather than liquid water.
+    fn is_over_ice(&self, t_dry_bulb: f64) -> bool {
+        let triple_point = if self.is_ip() {
+            TRIPLE_POINT_WATER_IP
+        } else {
+            TRIPLE_POINT_WATER_SI
+        };
+
+        match self.config.saturation_phase {
+            SaturationPhase::Automatic => t_dry_bulb <= triple_point,
+            SaturationPhase::Water => false,
+            SaturationPhase::Ice => true,
+        }
+    }
+}
+
+/******************************************************************************************************
+ * Conversion between temperature units
+ *****************************************************************************************************/
+
+/// Utility function to convert temperature to degree Rankine (°R) given temperature in degree Fahrenheit (°F).
+///
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 section 3
+pub fn get_t_rankine_from_t_fahrenheit(t_fahrenheit: f64) -> f64 {
+    t_fahrenheit + ZERO_FARENHEIT_AS_RANKINE
+}
+
+/// Utility function to convert temperature to degree Fahrenheit (°F) given temperature in degree Rankine (°R).
+///
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 section 3
+pub fn get_t_fahrenheit_from_t_rankine(t_rankine: f64) -> f64 {
+    t_rankine - ZERO_FARENHEIT_AS_RANKINE
+}
+
+/// Utility function to convert temperature to Kelvin (K) given temperature in degree Celsius (°C).
+///
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 section 3
+pub fn get_t_kelvin_from_t_celsius(t_celsius: f64) -> f64 {
+    t_celsius + ZERO_CELCIUS_AS_KELVIN
+}
+
+/// Utility function to convert temperature to degree Celsius (°C) given temperature in Kelvin (K).
+///
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 section 3
+pub fn get_t_celsius_from_t_kelvin(t_kelvin: f64) -> f64 {
+    t_kelvin - ZERO_CELCIUS_AS_KELVIN
+}
+
+/******************************************************************************************************
+ * Conversion between pressure units
+ *****************************************************************************************************/
+
+/// PressureUnit describes a unit in which a pressure may be given to, or read from, a Psychrolib
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum PressureUnit {
+    /// Pascal (Pa), the pressure unit of SI
+    Pa,
+    /// Kilopascal (kPa)
+    KPa,
+    /// Hectopascal (hPa), as reported by most weather stations
+    HPa,
+    /// Millibar (mbar), equal to the hectopascal
+    Mbar,
+    /// Bar (bar)
+    Bar,
+    /// Pound-force per square inch (Psi), the pressure unit of IP
+    Psi,
+    /// Inch of mercury (inHg)
+    InHg,
+    /// Millimetre of mercury (mmHg)
+    MmHg,
+    /// Standard atmosphere (atm)
+    Atm,
+}
+
+impl PressureUnit {
+    /// Returns one of this unit expressed in Pascal (Pa)
+    pub fn as_pascal(self) -> f64 {
+        match self {
+            PressureUnit::Pa => 1.0,
+            PressureUnit::KPa => 1000.0,
+            PressureUnit::HPa | PressureUnit::Mbar => 100.0,
+            PressureUnit::Bar => 100000.0,
+            PressureUnit::Psi => PSI_AS_PASCAL,
+            PressureUnit::InHg => INHG_AS_PASCAL,
+            PressureUnit::MmHg => MMHG_AS_PASCAL,
+            PressureUnit::Atm => ATM_AS_PASCAL,
+        }
+    }
+
+    /// Utility function to convert a pressure given in this unit to Pascal (Pa).
+    ///
+    /// # Example
+    ///     use psychrolib::PressureUnit;
+    ///
+    ///     assert_eq!(PressureUnit::HPa.to_pascal(1013.25), 101325.0);
+    pub fn to_pascal(self, pressure: f64) -> f64 {
+        pressure * self.as_pascal()
+    }
+
+    /// Utility function to convert a pressure in Pascal (Pa) to this unit.
+    pub fn from_pascal(self, pressure: f64) -> f64 {
+        pressure / self.as_pascal()
+    }
+}
 
-        assert_eq!(psych.get_t_rankine_from_t_fahrenheit());
+impl fmt::Display for PressureUnit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let label = match self {
+            PressureUnit::Pa => "Pa",
+            PressureUnit::KPa => "kPa",
+            PressureUnit::HPa => "hPa",
+            PressureUnit::Mbar => "mbar",
+            PressureUnit::Bar => "bar",
+            PressureUnit::Psi => "Psi",
+            PressureUnit::InHg => "inHg",
+            PressureUnit::MmHg => "mmHg",
+            PressureUnit::Atm => "atm",
+        };
+        write!(f, "{}", label)
+    }
+}
 
+impl<U: Units> Psychrolib<U> {
+    /// Returns a pressure in Psi [IP] or Pa [SI], given the pressure in any PressureUnit. The result may be
+    /// passed as any pressure argument of this Psychrolib.
+    ///
+    /// # Example
+    ///     use psychrolib::{PressureUnit, Psychrolib, UnitSystem};
+    ///
+    ///     let psych = Psychrolib::new(UnitSystem::IP);
+    ///     let pressure = psych.get_pressure_from_unit(29.92, PressureUnit::InHg);
+    ///
+    ///     assert!((pressure - 14.696).abs() < 0.001);
+    pub fn get_pressure_from_unit(&self, pressure: f64, unit: PressureUnit) -> U::Pressure {
+        let pressure = unit.to_pascal(pressure);
+        if self.is_ip() {
+            (pressure / PSI_AS_PASCAL).into_quantity()
+        } else {
+            pressure.into_quantity()
+        }
+    }
+
+    /// Returns a pressure in the given PressureUnit, given the pressure in Psi [IP] or Pa [SI].
+    ///
+    /// # Example
+    ///     use psychrolib::{PressureUnit, Psychrolib, UnitSystem};
+    ///
+    ///     let psych = Psychrolib::new(UnitSystem::SI);
+    ///     let pressure = psych.get_standard_atm_pressure(0.0);
+    ///
+    ///     assert_eq!(psych.get_pressure_in_unit(pressure, PressureUnit::HPa), 1013.25);
+    pub fn get_pressure_in_unit(&self, pressure: U::Pressure, unit: PressureUnit) -> f64 {
+        let pressure: f64 = pressure.into();
+        let pressure = if self.is_ip() {
+            pressure * PSI_AS_PASCAL
+        } else {
+            pressure
+        };
+
+        unit.from_pascal(pressure)
+    }
+}
+
+/******************************************************************************************************
+ * Conversions between dew point, wet bulb, and relative humidity
+ *****************************************************************************************************/
+
+impl<U: Units> Psychrolib<U> {
+    /// Returns wet-bulb temperature in °F [IP] or °C [SI] given dry-bulb temperature in °F [IP] or °C [SI],
+    /// dew-point temperature in °F [IP] or °C [SI], and pressure in Psi [IP] or Pa [SI].
+    ///
+    /// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1
+    pub fn get_t_wet_bulb_from_t_dew_point(
+        &self,
+        t_dry_bulb: U::Temperature,
+        t_dew_point: U::Temperature,
+        pressure: U::Pressure,
+    ) -> Result<U::Temperature, PsychroError> {
+        let t_dew_point =
+            self.check_below_t_dry_bulb(Property::TDewPoint, t_dew_point, t_dry_bulb)?;
+
+        let hum_ratio = self.get_hum_ratio_from_t_dew_point(t_dew_point, pressure)?;
+        self.get_t_wet_bulb_from_hum_ratio(t_dry_bulb, hum_ratio, pressure)
+    }
+
+    /// Returns wet-bulb temperature in °F [IP] or °C [SI] given dry-bulb temperature in °F [IP] or °C [SI],
+    /// relative humidity in range [0, 1], and pressure in Psi [IP] or Pa [SI].
+    ///
+    /// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1
+    ///
+    /// # Example
+    ///     use psychrolib::{Psychrolib, UnitSystem};
+    ///
+    ///     let psych = Psychrolib::new(UnitSystem::SI);
+    ///     let t_wet_bulb = psych.get_t_wet_bulb_from_rel_hum(7.0, 0.61, 100000.0).unwrap();
+    ///
+    ///     assert!((t_wet_bulb - 3.92667433781955).abs() < 0.004);
+    pub fn get_t_wet_bulb_from_rel_hum(
+        &self,
+        t_dry_bulb: U::Temperature,
+        rel_hum: U::RelHum,
+        pressure: U::Pressure,
+    ) -> Result<U::Temperature, PsychroError> {
+        let rel_hum = self.check_rel_hum(rel_hum)?.into_quantity();
+
+        let hum_ratio = self.get_hum_ratio_from_rel_hum(t_dry_bulb, rel_hum, pressure)?;
+        self.get_t_wet_bulb_from_hum_ratio(t_dry_bulb, hum_ratio, pressure)
+    }
+
+    /// Returns relative humidity in range [0, 1] given dry-bulb temperature in °F [IP] or °C [SI],
+    /// wet-bulb temperature in °F [IP] or °C [SI], and pressure in Psi [IP] or Pa [SI].
+    ///
+    /// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1
+    pub fn get_rel_hum_from_t_wet_bulb(
+        &self,
+        t_dry_bulb: U::Temperature,
+        t_wet_bulb: U::Temperature,
+        pressure: U::Pressure,
+    ) -> Result<U::RelHum, PsychroError> {
+        let t_wet_bulb = self.check_below_t_dry_bulb(Property::TWetBulb, t_wet_bulb, t_dry_bulb)?;
+
+        let hum_ratio = self.get_hum_ratio_from_t_wet_bulb(t_dry_bulb, t_wet_bulb, pressure)?;
+        self.get_rel_hum_from_hum_ratio(t_dry_bulb, hum_ratio, pressure)
+    }
+
+    /// Returns dew-point temperature in °F [IP] or °C [SI] given dry-bulb temperature in °F [IP] or °C [SI],
+    /// wet-bulb temperature in °F [IP] or °C [SI], and pressure in Psi [IP] or Pa [SI].
+    ///
+    /// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1
+    pub fn get_t_dew_point_from_t_wet_bulb(
+        &self,
+        t_dry_bulb: U::Temperature,
+        t_wet_bulb: U::Temperature,
+        pressure: U::Pressure,
+    ) -> Result<U::Temperature, PsychroError> {
+        let t_wet_bulb = self.check_below_t_dry_bulb(Property::TWetBulb, t_wet_bulb, t_dry_bulb)?;
+
+        let hum_ratio = self.get_hum_ratio_from_t_wet_bulb(t_dry_bulb, t_wet_bulb, pressure)?;
+        self.get_t_dew_point_from_hum_ratio(t_dry_bulb, hum_ratio, pressure)
+    }
+
+    /// Returns relative humidity in range [0, 1] given dry-bulb temperature in °F [IP] or °C [SI]
+    /// and dew-point temperature in °F [IP] or °C [SI].
+    ///
+    /// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 22
+    pub fn get_rel_hum_from_t_dew_point(
+        &self,
+        t_dry_bulb: U::Temperature,
+        t_dew_point: U::Temperature,
+    ) -> Result<U::RelHum, PsychroError> {
+        let t_dew_point =
+            self.check_below_t_dry_bulb(Property::TDewPoint, t_dew_point, t_dry_bulb)?;
+
+        let vap_pres: f64 = self.get_sat_vap_pres(t_dew_point)?.into();
+        let sat_vap_pres: f64 = self.get_sat_vap_pres(t_dry_bulb)?.into();
+        Ok((vap_pres / sat_vap_pres).into_quantity())
+    }
+
+    /// Returns dew-point temperature in °F [IP] or °C [SI] given dry-bulb temperature in °F [IP] or °C [SI]
+    /// and relative humidity in range [0, 1].
+    ///
+    /// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1
+    ///
+    /// # Example
+    ///     use psychrolib::{Psychrolib, UnitSystem};
+    ///
+    ///     let psych = Psychrolib::new(UnitSystem::SI);
+    ///     let t_dew_point = psych.get_t_dew_point_from_rel_hum(25.0, 0.80).unwrap();
+    ///
+    ///     assert!((t_dew_point - 21.309397163661785).abs() < 0.001);
+    pub fn get_t_dew_point_from_rel_hum(
+        &self,
+        t_dry_bulb: U::Temperature,
+        rel_hum: U::RelHum,
+    ) -> Result<U::Temperature, PsychroError> {
+        let rel_hum = self.check_rel_hum(rel_hum)?.into_quantity();
+
+        let vap_pres = self.get_vap_pres_from_rel_hum(t_dry_bulb, rel_hum)?;
+        self.get_t_dew_point_from_vap_pres(t_dry_bulb, vap_pres)
+    }
+}
+
+/******************************************************************************************************
+ * Conversions between dew point, or relative humidity and vapor pressure
+ *****************************************************************************************************/
+
+impl<U: Units> Psychrolib<U> {
+    /// Returns partial pressure of water vapor in moist air in Psi [IP] or Pa [SI] given dry-bulb temperature
+    /// in °F [IP] or °C [SI] and relative humidity in range [0, 1].
+    ///
+    /// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 12, 22
+    pub fn get_vap_pres_from_rel_hum(
+        &self,
+        t_dry_bulb: U::Temperature,
+        rel_hum: U::RelHum,
+    ) -> Result<U::Pressure, PsychroError> {
+        let rel_hum = self.check_rel_hum(rel_hum)?;
+
+        let sat_vap_pres: f64 = self.get_sat_vap_pres(t_dry_bulb)?.into();
+        Ok((rel_hum * sat_vap_pres).into_quantity())
+    }
+
+    /// Returns relative humidity in range [0, 1] given dry-bulb temperature in °F [IP] or °C [SI]
+    /// and partial pressure of water vapor in moist air in Psi [IP] or Pa [SI].
+    ///
+    /// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 12, 22
+    pub fn get_rel_hum_from_vap_pres(
+        &self,
+        t_dry_bulb: U::Temperature,
+        vap_pres: U::Pressure,
+    ) -> Result<U::RelHum, PsychroError> {
+        let vap_pres = self.check_vap_pres(vap_pres)?;
+
+        let sat_vap_pres: f64 = self.get_sat_vap_pres(t_dry_bulb)?.into();
+        Ok((vap_pres / sat_vap_pres).into_quantity())
+    }
+
+    /// Returns dew-point temperature in °F [IP] or °C [SI] given dry-bulb temperature in °F [IP] or °C [SI]
+    /// and vapor pressure in Psi [IP] or Pa [SI].
+    ///
+    /// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn. 5 and 6
+    ///
+    /// The dew point temperature is solved by inverting the equation giving water vapor pressure
+    /// at saturation from temperature rather than using the regressions provided
+    /// by ASHRAE (eqn. 37 and 38) which are much less accurate and have a
+    /// narrower range of validity.
+    /// The Newton-Raphson (NR) method is used on the logarithm of water vapour
+    /// pressure as a function of temperature, which is a very smooth function
+    /// Convergence is usually achieved in 3 to 5 iterations.
+    /// t_dry_bulb is not really needed here, just used for convenience.
+    ///
+    /// # Example
+    ///     use psychrolib::{Psychrolib, UnitSystem};
+    ///
+    ///     let psych = Psychrolib::new(UnitSystem::SI);
+    ///     let t_dew_point = psych.get_t_dew_point_from_vap_pres(25.0, 2000.0).unwrap();
+    ///
+    ///     assert!((t_dew_point - 17.5).abs() < 0.1);
+    pub fn get_t_dew_point_from_vap_pres(
+        &self,
+        t_dry_bulb: U::Temperature,
+        vap_pres: U::Pressure,
+    ) -> Result<U::Temperature, PsychroError> {
+        let (t_dew_point, converged) = self.solve_t_dew_point(t_dry_bulb, vap_pres, None, None)?;
+        if !converged {
+            return Err(self.convergence_not_reached("get_t_dew_point_from_vap_pres"));
+        }
+
+        Ok(t_dew_point.into_quantity())
+    }
+
+    /// Returns dew-point temperature in °F [IP] or °C [SI] given dry-bulb temperature in °F [IP] or °C [SI]
+    /// and vapor pressure in Psi [IP] or Pa [SI], together with a report of the Newton-Raphson iterations.
+    ///
+    /// See get_t_dew_point_from_vap_pres. When the solver does not converge, the last estimate is returned
+    /// with a report whose `converged` is false, instead of an error.
+    ///
+    /// # Example
+    ///     use psychrolib::{Psychrolib, UnitSystem};
+    ///
+    ///     let psych = Psychrolib::new(UnitSystem::SI);
+    ///     let (t_dew_point, report) = psych
+    ///         .get_t_dew_point_from_vap_pres_with_diagnostics(25.0, 2000.0)
+    ///         .unwrap();
+    ///
+    ///     assert!((t_dew_point - 17.5).abs() < 0.1);
+    ///     assert!(report.converged && report.iterations <= 5);
+    pub fn get_t_dew_point_from_vap_pres_with_diagnostics(
+        &self,
+        t_dry_bulb: U::Temperature,
+        vap_pres: U::Pressure,
+    ) -> Result<(U::Temperature, SolverReport), PsychroError> {
+        let mut report = SolverReport::default();
+        let (t_dew_point, _) =
+            self.solve_t_dew_point(t_dry_bulb, vap_pres, None, Some(&mut report))?;
+
+        Ok((t_dew_point.into_quantity(), report))
+    }
+
+    // Solves for the dew-point temperature, see get_t_dew_point_from_vap_pres, starting from `seed` if given
+    // or from the dry-bulb temperature. Returns the last estimate and whether it converged within the max
+    // number of iterations, and records the iterations in `report`.
+    fn solve_t_dew_point(
+        &self,
+        t_dry_bulb: U::Temperature,
+        vap_pres: U::Pressure,
+        seed: Option<f64>,
+        mut report: Option<&mut SolverReport>,
+    ) -> Result<(f64, bool), PsychroError> {
+        let t_dry_bulb: f64 = t_dry_bulb.into();
+        let (t_min, t_max) = self.units.t_dry_bulb_bounds();
+        let bounds = [t_min, t_max];
+
+        // Validity check -- bounds outside which a solution cannot be found
+        let vap_pres_bounds: [f64; 2] = [
+            self.get_sat_vap_pres(bounds[0].into_quantity())?.into(),
+            self.get_sat_vap_pres(bounds[1].into_quantity())?.into(),
+        ];
+        let vap_pres = self.check_range(
+            Property::VapPres,
+            vap_pres.into(),
+            vap_pres_bounds[0],
+            vap_pres_bounds[1],
+        )?;
+        if vap_pres.is_nan() || t_dry_bulb.is_nan() {
+            if let Some(report) = report {
+                report.residual = f64::NAN;
+                report.converged = true;
+            }
+            return Ok((f64::NAN, true));
+        }
+
+        // We use NR to approximate the solution.
+        // First guess: the dry-bulb temperature, or the seed of a warm start
+        // t_dew_point is the calculated value of dew point temperatures, solved for iteratively
+        let mut t_dew_point = seed.map_or(t_dry_bulb, |seed| seed.max(bounds[0]).min(bounds[1]));
+        let ln_vp = vap_pres.ln(); // Partial pressure of water vapor in moist air
+        let mut bracket = bounds; // The saturation vapor pressure increases with temperature
+
+        let mut index = 1;
+
+        let converged = loop {
+            let t_dew_point_iter = t_dew_point; // t_dew_point used in NR calculation
+            let vp_iter: f64 = self
+                .get_sat_vap_pres(t_dew_point_iter.into_quantity())?
+                .into();
+            let ln_vp_iter = vp_iter.ln();
+
+            // Derivative of function, calculated analytically
+            let d_ln_vp = self.d_ln_pws(t_dew_point_iter.into_quantity());
+
+            // New estimate, bounded by the search domain defined above
+            let t_dew_point_nr = t_dew_point_iter - (ln_vp_iter - ln_vp) / d_ln_vp;
+            t_dew_point = t_dew_point_nr.max(bounds[0]).min(bounds[1]);
+            let residual = (t_dew_point - t_dew_point_iter).abs();
+
+            if let Some(report) = report.as_deref_mut() {
+                if ln_vp_iter > ln_vp {
+                    bracket[1] = bracket[1].min(t_dew_point_iter);
+                } else {
+                    bracket[0] = bracket[0].max(t_dew_point_iter);
+                }
+                report.record(index, t_dew_point, bracket[0], bracket[1]);
+                report.residual = residual;
+                report.hit_bound |= t_dew_point != t_dew_point_nr;
+            }
+
+            if residual <= self.get_tolerance() {
+                break true;
+            }
+
+            if index >= self.config.max_iter_count {
+                break false;
+            }
+
+            index += 1;
+        };
+
+        if let Some(report) = report {
+            report.converged = converged;
+        }
+
+        Ok((t_dew_point.min(t_dry_bulb), converged))
+    }
+
+    /// Returns vapor pressure in Psi [IP] or Pa [SI] given dew-point temperature in °F [IP] or °C [SI].
+    ///
+    /// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 36
+    pub fn get_vap_pres_from_t_dew_point(
+        &self,
+        t_dew_point: U::Temperature,
+    ) -> Result<U::Pressure, PsychroError> {
+        self.get_sat_vap_pres(t_dew_point)
+    }
+}
+
+/******************************************************************************************************
+ * Conversions from wet-bulb temperature, dew-point temperature, or relative humidity to humidity ratio
+ *****************************************************************************************************/
+
+impl<U: Units> Psychrolib<U> {
+    /// Returns wet-bulb temperature in °F [IP] or °C [SI] given dry-bulb temperature in °F [IP] or °C [SI],
+    /// humidity ratio in lb_H₂O lb_Air⁻¹ [IP] or kg_H₂O kg_Air⁻¹ [SI], and pressure in Psi [IP] or Pa [SI].
+    ///
+    /// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 33 and 35 solved for Tstar
+    ///
+    /// The wet-bulb temperature is found by bisection between the dew-point and dry-bulb temperatures,
+    /// or by the root finder selected with with_wet_bulb_solver.
+    pub fn get_t_wet_bulb_from_hum_ratio(
+        &self,
+        t_dry_bulb: U::Temperature,
+        hum_ratio: U::HumRatio,
+        pressure: U::Pressure,
+    ) -> Result<U::Temperature, PsychroError> {
+        let t_dew_point = self.get_t_dew_point_from_hum_ratio(t_dry_bulb, hum_ratio, pressure)?;
+        let (t_wet_bulb, converged) =
+            self.solve_t_wet_bulb(t_dry_bulb, hum_ratio, pressure, t_dew_point, None, None)?;
+        if !converged {
+            return Err(self.convergence_not_reached("get_t_wet_bulb_from_hum_ratio"));
+        }
+
+        Ok(t_wet_bulb.into_quantity())
+    }
+
+    /// Returns wet-bulb temperature in °F [IP] or °C [SI] given dry-bulb temperature in °F [IP] or °C [SI],
+    /// humidity ratio in lb_H₂O lb_Air⁻¹ [IP] or kg_H₂O kg_Air⁻¹ [SI], and pressure in Psi [IP] or Pa [SI],
+    /// together with a report of the iterations of the root finder.
+    ///
+    /// See get_t_wet_bulb_from_hum_ratio. When the solver does not converge, the last estimate is returned
+    /// with a report whose `converged` is false, instead of an error.
+    ///
+    /// # Example
+    ///     use psychrolib::{Psychrolib, UnitSystem};
+    ///
+    ///     let psych = Psychrolib::new(UnitSystem::SI).with_max_iter_count(5);
+    ///     let (t_wet_bulb, report) = psych
+    ///         .get_t_wet_bulb_from_hum_ratio_with_diagnostics(25.0, 0.01, 101325.0)
+    ///         .unwrap();
+    ///
+    ///     assert!(!report.converged);
+    ///     assert_eq!(report.iterations, 5);
+    ///     assert!(report.residual > psych.get_tolerance());
+    pub fn get_t_wet_bulb_from_hum_ratio_with_diagnostics(
+        &self,
+        t_dry_bulb: U::Temperature,
+        hum_ratio: U::HumRatio,
+        pressure: U::Pressure,
+    ) -> Result<(U::Temperature, SolverReport), PsychroError> {
+        let t_dew_point = self.get_t_dew_point_from_hum_ratio(t_dry_bulb, hum_ratio, pressure)?;
+        let mut report = SolverReport::default();
+        let (t_wet_bulb, _) = self.solve_t_wet_bulb(
+            t_dry_bulb,
+            hum_ratio,
+            pressure,
+            t_dew_point,
+            None,
+            Some(&mut report),
+        )?;
+
+        Ok((t_wet_bulb.into_quantity(), report))
+    }
+
+    // Solves for the wet-bulb temperature, see get_t_wet_bulb_from_hum_ratio, given the dew-point temperature.
+    // Returns the last estimate and whether it converged within the max number of iterations, and records
+    // the iterations in `report`.
+    //
+    // With a `seed`, the bisection does not start from the whole interval between the dew-point and dry-bulb
+    // temperatures. The humidity ratio is first evaluated at the seed, then at points moving away from it with
+    // doubling steps, until the solution is bracketed. A seed close to the solution thus gives a narrow bracket.
+    fn solve_t_wet_bulb(
+        &self,
+        t_dry_bulb: U::Temperature,
+        hum_ratio: U::HumRatio,
+        pressure: U::Pressure,
+        t_dew_point: U::Temperature,
+        seed: Option<f64>,
+        mut report: Option<&mut SolverReport>,
+    ) -> Result<(f64, bool), PsychroError> {
+        let bounded_hum_ratio = self.bounded_hum_ratio(hum_ratio)?;
+        let t_dew_point: f64 = t_dew_point.into();
+
+        // Difference between the humidity ratio at wet-bulb temperature Tstar and the humidity ratio,
+        // which increases with Tstar. As bounded_hum_ratio is not below the minimum humidity ratio, the
+        // sign is the same whether the humidity ratio at Tstar is raised to the minimum or not.
+        let mut residual = |t_wet_bulb: f64| -> Result<f64, PsychroError> {
+            let w_star = self.unbounded_hum_ratio_from_t_wet_bulb(
+                t_dry_bulb,
+                t_wet_bulb.into_quantity(),
+                pressure,
+            )?;
+            Ok(w_star - bounded_hum_ratio)
+        };
+
+        // Initial guesses, and residuals at the bounds once known
+        let mut t_wet_bulb_sup: f64 = t_dry_bulb.into();
+        let mut t_wet_bulb_inf = t_dew_point;
+        let mut residual_sup = None;
+        let mut residual_inf = None;
+
+        let mut index = 1;
+        let mut converged = true;
+
+        if let Some(seed) = seed {
+            let mut t_wet_bulb = seed;
+            let mut step = WARM_START_STEP * self.get_tolerance();
+            let mut was_above = None;
+            // Bracketing loop
+            while t_wet_bulb_inf < t_wet_bulb && t_wet_bulb < t_wet_bulb_sup {
+                let r = residual(t_wet_bulb)?;
+
+                // Get new bounds, and move away from the seed until crossing the solution
+                let is_above = r > 0.0;
+                if is_above {
+                    t_wet_bulb_sup = t_wet_bulb;
+                    residual_sup = Some(r);
+                    t_wet_bulb -= step;
+                } else {
+                    t_wet_bulb_inf = t_wet_bulb;
+                    residual_inf = Some(r);
+                    t_wet_bulb += step;
+                }
+                step *= 2.0;
+
+                if let Some(report) = report.as_deref_mut() {
+                    report.record(index, t_wet_bulb, t_wet_bulb_inf, t_wet_bulb_sup);
+                }
+
+                if index >= self.config.max_iter_count {
+                    converged = false;
+                    break;
+                }
+
+                index += 1;
+
+                if was_above == Some(!is_above) {
+                    break;
+                }
+                was_above = Some(is_above);
+            }
+        }
+
+        let mut t_wet_bulb = (t_wet_bulb_inf + t_wet_bulb_sup) / 2.0;
+
+        // The humidity ratio jumps at the freezing point, where the equations switch between ice and water,
+        // so that there may be a solution on each side. Brent's method is only used once the freezing point
+        // is out of the bracket, to settle on the same solution as bisection.
+        let freezing_point = if self.is_ip() {
+            FREEZING_POINT_WATER_IP
+        } else {
+            FREEZING_POINT_WATER_SI
+        };
+        let bisect = |inf: f64, sup: f64| {
+            self.config.wet_bulb_solver == WetBulbSolver::Bisection
+                || (inf < freezing_point && freezing_point < sup)
+        };
+
+        // Bisection loop
+        while converged
+            && (t_wet_bulb_sup - t_wet_bulb_inf) > self.get_tolerance()
+            && bisect(t_wet_bulb_inf, t_wet_bulb_sup)
+        {
+            // Get new bounds
+            let r = residual(t_wet_bulb)?;
+            if r > 0.0 {
+                t_wet_bulb_sup = t_wet_bulb;
+                residual_sup = Some(r);
+            } else {
+                t_wet_bulb_inf = t_wet_bulb;
+                residual_inf = Some(r);
+            }
+
+            // New guess of wet bulb temperature
+            t_wet_bulb = (t_wet_bulb_sup + t_wet_bulb_inf) / 2.0;
+
+            if let Some(report) = report.as_deref_mut() {
+                report.record(index, t_wet_bulb, t_wet_bulb_inf, t_wet_bulb_sup);
+            }
+
+            if index >= self.config.max_iter_count {
+                converged = false;
+                break;
+            }
+
+            index += 1;
+        }
+
+        if converged && (t_wet_bulb_sup - t_wet_bulb_inf) > self.get_tolerance() {
+            // Residuals at the bounds not evaluated yet count as iterations, up to the max number of iterations
+            let max_iter_count = self.config.max_iter_count;
+            let mut residual_at = |t: f64, known: Option<f64>| match known {
+                Some(r) => Ok(Some(r)),
+                None if index > max_iter_count => Ok(None),
+                None => {
+                    index += 1;
+                    residual(t).map(Some)
+                }
+            };
+            let residual_inf = residual_at(t_wet_bulb_inf, residual_inf)?;
+            let residual_sup = residual_at(t_wet_bulb_sup, residual_sup)?;
+            if let Some(report) = report.as_deref_mut() {
+                report.iterations = index - 1;
+            }
+
+            match (residual_inf, residual_sup) {
+                // Round-off may leave the solution at a bound, outside the bracket
+                (Some(residual_inf), _) if residual_inf > 0.0 => {
+                    t_wet_bulb = t_wet_bulb_inf;
+                    t_wet_bulb_sup = t_wet_bulb_inf;
+                }
+                (_, Some(residual_sup)) if residual_sup <= 0.0 => {
+                    t_wet_bulb = t_wet_bulb_sup;
+                    t_wet_bulb_inf = t_wet_bulb_sup;
+                }
+                (Some(residual_inf), Some(residual_sup)) if index <= max_iter_count => {
+                    let brent = solve::brent_bracketed(
+                        self,
+                        &mut residual,
+                        [t_wet_bulb_inf, t_wet_bulb_sup],
+                        [residual_inf, residual_sup],
+                        index,
+                        report.as_deref_mut(),
+                    )?;
+                    t_wet_bulb = brent.0;
+                    t_wet_bulb_inf = brent.1[0];
+                    t_wet_bulb_sup = brent.1[1];
+                    converged = brent.2;
+                }
+                _ => converged = false,
+            }
+        }
+
+        if let Some(report) = report {
+            report.residual = t_wet_bulb_sup - t_wet_bulb_inf;
+            report.converged = converged;
+            report.hit_bound = t_wet_bulb_inf == t_dew_point || t_wet_bulb_sup == t_dry_bulb.into();
+        }
+
+        Ok((t_wet_bulb, converged))
+    }
+
+    /// Returns humidity ratio in lb_H₂O lb_Air⁻¹ [IP] or kg_H₂O kg_Air⁻¹ [SI] given dry-bulb temperature
+    /// in °F [IP] or °C [SI], wet-bulb temperature in °F [IP] or °C [SI], and pressure in Psi [IP] or Pa [SI].
+    ///
+    /// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 33 and 35
+    pub fn get_hum_ratio_from_t_wet_bulb(
+        &self,
+        t_dry_bulb: U::Temperature,
+        t_wet_bulb: U::Temperature,
+        pressure: U::Pressure,
+    ) -> Result<U::HumRatio, PsychroError> {
+        let hum_ratio =
+            self.unbounded_hum_ratio_from_t_wet_bulb(t_dry_bulb, t_wet_bulb, pressure)?;
+
+        // Validity check.
+        Ok(self.clamp_hum_ratio(hum_ratio).into_quantity())
+    }
+
+    // Returns the humidity ratio given by get_hum_ratio_from_t_wet_bulb before it is raised to the minimum
+    // humidity ratio. It increases with the wet-bulb temperature, which the wet-bulb solvers rely on.
+    fn unbounded_hum_ratio_from_t_wet_bulb(
+        &self,
+        t_dry_bulb: U::Temperature,
+        t_wet_bulb: U::Temperature,
+        pressure: U::Pressure,
+    ) -> Result<f64, PsychroError> {
+        let t_wet_bulb = self.check_below_t_dry_bulb(Property::TWetBulb, t_wet_bulb, t_dry_bulb)?;
+
+        let ws_star: f64 = self.get_sat_hum_ratio(t_wet_bulb, pressure)?.into();
+        let t_dry_bulb: f64 = t_dry_bulb.into();
+        let t_wet_bulb: f64 = t_wet_bulb.into();
+        let freezing_point = if self.is_ip() {
+            FREEZING_POINT_WATER_IP
+        } else {
+            FREEZING_POINT_WATER_SI
+        };
+        // The wet bulb is covered with the phase saturation is taken over, so that the latent heat matches
+        // ws_star: an ice bulb at all temperatures over ice and a supercooled water bulb over water.
+        let water_bulb = match self.config.saturation_phase {
+            SaturationPhase::Automatic => t_wet_bulb >= freezing_point,
+            SaturationPhase::Water => true,
+            SaturationPhase::Ice => false,
+        };
+        let hum_ratio;
+
+        if self.is_ip() {
+            if water_bulb {
+                hum_ratio = ((1093.0 - 0.556 * t_wet_bulb) * ws_star
+                    - 0.240 * (t_dry_bulb - t_wet_bulb))
+                    / (1093.0 + 0.444 * t_dry_bulb - t_wet_bulb);
+            } else {
+                hum_ratio = ((1220.0 - 0.04 * t_wet_bulb) * ws_star
+                    - 0.240 * (t_dry_bulb - t_wet_bulb))
+                    / (1220.0 + 0.444 * t_dry_bulb - 0.48 * t_wet_bulb);
+            }
+        } else if water_bulb {
+            hum_ratio = ((2501.0 - 2.326 * t_wet_bulb) * ws_star
+                - 1.006 * (t_dry_bulb - t_wet_bulb))
+                / (2501.0 + 1.86 * t_dry_bulb - 4.186 * t_wet_bulb);
+        } else {
+            hum_ratio = ((2830.0 - 0.24 * t_wet_bulb) * ws_star
+                - 1.006 * (t_dry_bulb - t_wet_bulb))
+                / (2830.0 + 1.86 * t_dry_bulb - 2.1 * t_wet_bulb);
+        }
+
+        Ok(hum_ratio)
+    }
+
+    /// Returns humidity ratio in lb_H₂O lb_Air⁻¹ [IP] or kg_H₂O kg_Air⁻¹ [SI] given dry-bulb temperature
+    /// in °F [IP] or °C [SI], relative humidity in range [0, 1], and pressure in Psi [IP] or Pa [SI].
+    ///
+    /// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1
+    pub fn get_hum_ratio_from_rel_hum(
+        &self,
+        t_dry_bulb: U::Temperature,
+        rel_hum: U::RelHum,
+        pressure: U::Pressure,
+    ) -> Result<U::HumRatio, PsychroError> {
+        let rel_hum = self.check_rel_hum(rel_hum)?;
+
+        let vap_pres = rel_hum * self.sat_vap_pres_in_moist_air(t_dry_bulb, pressure)?;
+        self.get_hum_ratio_from_vap_pres(vap_pres.into_quantity(), pressure)
+    }
+
+    /// Returns relative humidity in range [0, 1] given dry-bulb temperature in °F [IP] or °C [SI],
+    /// humidity ratio in lb_H₂O lb_Air⁻¹ [IP] or kg_H₂O kg_Air⁻¹ [SI], and pressure in Psi [IP] or Pa [SI].
+    ///
+    /// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1
+    pub fn get_rel_hum_from_hum_ratio(
+        &self,
+        t_dry_bulb: U::Temperature,
+        hum_ratio: U::HumRatio,
+        pressure: U::Pressure,
+    ) -> Result<U::RelHum, PsychroError> {
+        let vap_pres: f64 = self
+            .get_vap_pres_from_hum_ratio(hum_ratio, pressure)?
+            .into();
+        Ok((vap_pres / self.sat_vap_pres_in_moist_air(t_dry_bulb, pressure)?).into_quantity())
+    }
+
+    /// Returns humidity ratio in lb_H₂O lb_Air⁻¹ [IP] or kg_H₂O kg_Air⁻¹ [SI] given dew-point temperature
+    /// in °F [IP] or °C [SI] and pressure in Psi [IP] or Pa [SI].
+    ///
+    /// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 13
+    pub fn get_hum_ratio_from_t_dew_point(
+        &self,
+        t_dew_point: U::Temperature,
+        pressure: U::Pressure,
+    ) -> Result<U::HumRatio, PsychroError> {
+        let vap_pres = self.sat_vap_pres_in_moist_air(t_dew_point, pressure)?;
+        self.get_hum_ratio_from_vap_pres(vap_pres.into_quantity(), pressure)
+    }
+
+    /// Returns dew-point temperature in °F [IP] or °C [SI] given dry-bulb temperature in °F [IP] or °C [SI],
+    /// humidity ratio in lb_H₂O lb_Air⁻¹ [IP] or kg_H₂O kg_Air⁻¹ [SI], and pressure in Psi [IP] or Pa [SI].
+    ///
+    /// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1
+    pub fn get_t_dew_point_from_hum_ratio(
+        &self,
+        t_dry_bulb: U::Temperature,
+        hum_ratio: U::HumRatio,
+        pressure: U::Pressure,
+    ) -> Result<U::Temperature, PsychroError> {
+        let vap_pres = self.get_vap_pres_from_hum_ratio(hum_ratio, pressure)?;
+        self.t_dew_point_in_moist_air(vap_pres, pressure, |vap_pres| {
+            self.get_t_dew_point_from_vap_pres(t_dry_bulb, vap_pres)
+        })
+    }
+}
+
+/******************************************************************************************************
+ * Conversions between humidity ratio and vapor pressure
+ *****************************************************************************************************/
+
+impl<U: Units> Psychrolib<U> {
+    /// Returns humidity ratio in lb_H₂O lb_Air⁻¹ [IP] or kg_H₂O kg_Air⁻¹ [SI] given water vapor pressure
+    /// and atmospheric pressure in Psi [IP] or Pa [SI].
+    ///
+    /// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 20
+    pub fn get_hum_ratio_from_vap_pres(
+        &self,
+        vap_pres: U::Pressure,
+        pressure: U::Pressure,
+    ) -> Result<U::HumRatio, PsychroError> {
+        let vap_pres = self.check_vap_pres(vap_pres)?;
+        let pressure: f64 = pressure.into();
+
+        let hum_ratio = 0.621945 * vap_pres / (pressure - vap_pres);
+
+        // Validity check.
+        Ok(self.clamp_hum_ratio(hum_ratio).into_quantity())
+    }
+
+    /// Returns vapor pressure in Psi [IP] or Pa [SI] given humidity ratio in lb_H₂O lb_Air⁻¹ [IP]
+    /// or kg_H₂O kg_Air⁻¹ [SI] and pressure in Psi [IP] or Pa [SI].
+    ///
+    /// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 20 solved for pw
+    pub fn get_vap_pres_from_hum_ratio(
+        &self,
+        hum_ratio: U::HumRatio,
+        pressure: U::Pressure,
+    ) -> Result<U::Pressure, PsychroError> {
+        let bounded_hum_ratio = self.bounded_hum_ratio(hum_ratio)?;
+        let pressure: f64 = pressure.into();
+
+        Ok((pressure * bounded_hum_ratio / (0.621945 + bounded_hum_ratio)).into_quantity())
+    }
+}
+
+/******************************************************************************************************
+ * Conversions between humidity ratio and specific humidity
+ *****************************************************************************************************/
+
+impl<U: Units> Psychrolib<U> {
+    /// Returns the specific humidity in lb_H₂O lb_Air⁻¹ [IP] or kg_H₂O kg_Air⁻¹ [SI] from humidity ratio
+    /// (aka mixing ratio) in lb_H₂O lb_Dry_Air⁻¹ [IP] or kg_H₂O kg_Dry_Air⁻¹ [SI].
+    ///
+    /// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 9b
+    pub fn get_specific_hum_from_hum_ratio(
+        &self,
+        hum_ratio: U::HumRatio,
+    ) -> Result<f64, PsychroError> {
+        let bounded_hum_ratio = self.bounded_hum_ratio(hum_ratio)?;
+
+        Ok(bounded_hum_ratio / (1.0 + bounded_hum_ratio))
+    }
+
+    /// Returns the humidity ratio (aka mixing ratio) in lb_H₂O lb_Dry_Air⁻¹ [IP] or kg_H₂O kg_Dry_Air⁻¹ [SI]
+    /// from specific humidity in lb_H₂O lb_Air⁻¹ [IP] or kg_H₂O kg_Air⁻¹ [SI].
+    ///
+    /// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 9b (solved for humidity ratio)
+    pub fn get_hum_ratio_from_specific_hum(
+        &self,
+        specific_hum: f64,
+    ) -> Result<U::HumRatio, PsychroError> {
+        let specific_hum = if (0.0..1.0).contains(&specific_hum) {
+            specific_hum
+        } else {
+            let error = self.out_of_range(Property::SpecificHum, specific_hum, 0.0, 1.0);
+            let clamped = specific_hum.clamp(0.0, 1.0 - f64::EPSILON);
+            self.on_out_of_range(error, specific_hum, clamped)?
+        };
+
+        let hum_ratio = specific_hum / (1.0 - specific_hum);
+
+        // Validity check.
+        Ok(self.clamp_hum_ratio(hum_ratio).into_quantity())
+    }
+}
+
+/******************************************************************************************************
+ * Dry Air Calculations
+ *****************************************************************************************************/
+
+impl<U: Units> Psychrolib<U> {
+    /// Returns dry-air enthalpy in Btu lb⁻¹ [IP] or J kg⁻¹ [SI] given dry-bulb temperature in °F [IP] or °C [SI].
+    ///
+    /// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 28
+    pub fn get_dry_air_enthalpy(&self, t_dry_bulb: U::Temperature) -> U::Enthalpy {
+        let t_dry_bulb: f64 = t_dry_bulb.into();
+        let dry_air_enthalpy = if self.is_ip() {
+            0.240 * t_dry_bulb
+        } else {
+            1006.0 * t_dry_bulb
+        };
+
+        dry_air_enthalpy.into_quantity()
+    }
+
+    /// Returns dry-air density in lb ft⁻³ [IP] or kg m⁻³ [SI] given dry-bulb temperature in °F [IP] or °C [SI]
+    /// and pressure in Psi [IP] or Pa [SI].
+    ///
+    /// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1
+    ///
+    /// Eqn 14 for the perfect gas relationship for dry air.
+    /// Eqn 1 for the universal gas constant.
+    /// The factor 144 in IP is for the conversion of Psi = lb in⁻² to lb ft⁻².
+    pub fn get_dry_air_density(&self, t_dry_bulb: U::Temperature, pressure: U::Pressure) -> f64 {
+        let t_dry_bulb: f64 = t_dry_bulb.into();
+        let pressure: f64 = pressure.into();
+        if self.is_ip() {
+            (144.0 * pressure) / R_DA_IP / get_t_rankine_from_t_fahrenheit(t_dry_bulb)
+        } else {
+            pressure / R_DA_SI / get_t_kelvin_from_t_celsius(t_dry_bulb)
+        }
+    }
+
+    /// Returns dry-air volume in ft³ lb⁻¹ [IP] or in m³ kg⁻¹ [SI] given dry-bulb temperature in °F [IP] or °C [SI]
+    /// and pressure in Psi [IP] or Pa [SI].
+    ///
+    /// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1
+    ///
+    /// Eqn 14 for the perfect gas relationship for dry air.
+    /// Eqn 1 for the universal gas constant.
+    /// The factor 144 in IP is for the conversion of Psi = lb in⁻² to lb ft⁻².
+    pub fn get_dry_air_volume(&self, t_dry_bulb: U::Temperature, pressure: U::Pressure) -> f64 {
+        let t_dry_bulb: f64 = t_dry_bulb.into();
+        let pressure: f64 = pressure.into();
+        if self.is_ip() {
+            R_DA_IP * get_t_rankine_from_t_fahrenheit(t_dry_bulb) / (144.0 * pressure)
+        } else {
+            R_DA_SI * get_t_kelvin_from_t_celsius(t_dry_bulb) / pressure
+        }
+    }
+
+    /// Returns dry bulb temperature in °F [IP] or °C [SI] from moist air enthalpy in Btu lb⁻¹ [IP] or J kg⁻¹ [SI]
+    /// and humidity ratio in lb_H₂O lb_Air⁻¹ [IP] or kg_H₂O kg_Air⁻¹ [SI].
+    ///
+    /// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 30
+    ///
+    /// Based on the get_moist_air_enthalpy function, rearranged for temperature.
+    pub fn get_t_dry_bulb_from_enthalpy_and_hum_ratio(
+        &self,
+        moist_air_enthalpy: U::Enthalpy,
+        hum_ratio: U::HumRatio,
+    ) -> Result<U::Temperature, PsychroError> {
+        let bounded_hum_ratio = self.bounded_hum_ratio(hum_ratio)?;
+        let moist_air_enthalpy: f64 = moist_air_enthalpy.into();
+
+        let t_dry_bulb = if self.is_ip() {
+            (moist_air_enthalpy - 1061.0 * bounded_hum_ratio) / (0.240 + 0.444 * bounded_hum_ratio)
+        } else {
+            (moist_air_enthalpy / 1000.0 - 2501.0 * bounded_hum_ratio)
+                / (1.006 + 1.86 * bounded_hum_ratio)
+        };
+
+        Ok(t_dry_bulb.into_quantity())
+    }
+
+    /// Returns humidity ratio in lb_H₂O lb_Air⁻¹ [IP] or kg_H₂O kg_Air⁻¹ [SI] from moist air enthalpy
+    /// in Btu lb⁻¹ [IP] or J kg⁻¹ [SI] and dry-bulb temperature in °F [IP] or °C [SI].
+    ///
+    /// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 30
+    ///
+    /// Based on the get_moist_air_enthalpy function, rearranged for humidity ratio.
+    pub fn get_hum_ratio_from_enthalpy_and_t_dry_bulb(
+        &self,
+        moist_air_enthalpy: U::Enthalpy,
+        t_dry_bulb: U::Temperature,
+    ) -> U::HumRatio {
+        let moist_air_enthalpy: f64 = moist_air_enthalpy.into();
+        let t_dry_bulb: f64 = t_dry_bulb.into();
+        let hum_ratio = if self.is_ip() {
+            (moist_air_enthalpy - 0.240 * t_dry_bulb) / (1061.0 + 0.444 * t_dry_bulb)
+        } else {
+            (moist_air_enthalpy / 1000.0 - 1.006 * t_dry_bulb) / (2501.0 + 1.86 * t_dry_bulb)
+        };
+
+        // Validity check.
+        self.clamp_hum_ratio(hum_ratio).into_quantity()
+    }
+}
+
+/******************************************************************************************************
+ * Saturated Air Calculations
+ *****************************************************************************************************/
+
+impl<U: Units> Psychrolib<U> {
+    /// Returns saturation vapor pressure in Psi [IP] or Pa [SI] given dry-bulb temperature in °F [IP] or °C [SI].
+    ///
+    /// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 5 & 6, unless another saturation model
+    /// is selected with with_saturation_model
+    ///
+    /// Saturation is over ice below the triple point of water, unless another phase is selected with
+    /// with_saturation_phase.
+    ///
+    /// Important note: the ASHRAE formulae are defined above and below the freezing point but have
+    /// a discontinuity at the freezing point. This is a small inaccuracy on ASHRAE's part: the formulae
+    /// should be defined above and below the triple point of water (not the feezing point) in which case
+    /// the discontinuity vanishes. It is essential to use the triple point of water otherwise function
+    /// get_t_dew_point_from_vap_pres, which inverts the present function, does not converge properly around
+    /// the freezing point.
+    ///
+    /// # Example
+    ///     use psychrolib::{Psychrolib, UnitSystem};
+    ///
+    ///     let psych = Psychrolib::new(UnitSystem::SI);
+    ///     let sat_vap_pres = psych.get_sat_vap_pres(25.0).unwrap();
+    ///
+    ///     assert!((sat_vap_pres - 3169.7).abs() < 1.0);
+    pub fn get_sat_vap_pres(
+        &self,
+        t_dry_bulb: U::Temperature,
+    ) -> Result<U::Pressure, PsychroError> {
+        let (t_min, t_max) = self.units.t_dry_bulb_bounds();
+        let t_dry_bulb = self.check_range(Property::TDryBulb, t_dry_bulb.into(), t_min, t_max)?;
+        let over_ice = self.is_over_ice(t_dry_bulb);
+        let ln_pws;
+
+        if self.is_ip() && self.config.saturation_model == SaturationModel::Ashrae {
+            let t = get_t_rankine_from_t_fahrenheit(t_dry_bulb);
+
+            if over_ice {
+                ln_pws = -1.0214165E+04 / t - 4.8932428 - 5.3765794E-03 * t
+                    + 1.9202377E-07 * t.powi(2)
+                    + 3.5575832E-10 * t.powi(3)
+                    - 9.0344688E-14 * t.powi(4)
+                    + 4.1635019 * t.ln();
+            } else {
+                ln_pws = -1.0440397E+04 / t - 1.1294650E+01 - 2.7022355E-02 * t
+                    + 1.2890360E-05 * t.powi(2)
+                    - 2.4780681E-09 * t.powi(3)
+                    + 6.5459673 * t.ln();
+            }
+
+            return Ok(ln_pws.exp().into_quantity());
+        }
+
+        // Other formulations are computed in SI
+        let units = self.units.unit_system();
+        let t_dry_bulb = convert_temperature(t_dry_bulb, units, UnitSystem::SI);
+        ln_pws = self
+            .config
+            .saturation_model
+            .ln_sat_vap_pres(t_dry_bulb, over_ice);
+
+        Ok(convert_pressure(ln_pws.exp(), UnitSystem::SI, units).into_quantity())
+    }
+
+    /// Returns the enhancement factor of water vapor in moist air [unitless] given dry-bulb temperature
+    /// in °F [IP] or °C [SI] and pressure in Psi [IP] or Pa [SI]: the ratio of the saturation vapor pressure
+    /// of water vapor in moist air to that of pure water vapor, which ideal-gas mixing takes as 1.
+    ///
+    /// Reference: Greenspan L, J. Res. Natl. Bur. Stand. 80A, 41 (1976), with the coefficients for ITS-90
+    /// of Hardy B, ITS-90 formulations for vapor pressure, frostpoint temperature, dewpoint temperature and
+    /// enhancement factors in the range -100 to +100 C, Third International Symposium on Humidity and
+    /// Moisture (1998)
+    ///
+    /// # Example
+    ///     use psychrolib::{Psychrolib, UnitSystem};
+    ///
+    ///     let psych = Psychrolib::new(UnitSystem::SI);
+    ///     let enhancement_factor = psych.get_enhancement_factor(20.0, 101325.0).unwrap();
+    ///
+    ///     assert!((enhancement_factor - 1.0040).abs() < 0.0001);
+    pub fn get_enhancement_factor(
+        &self,
+        t_dry_bulb: U::Temperature,
+        pressure: U::Pressure,
+    ) -> Result<f64, PsychroError> {
+        let units = self.units.unit_system();
+        let sat_vap_pres = convert_pressure(
+            self.get_sat_vap_pres(t_dry_bulb)?.into(),
+            units,
+            UnitSystem::SI,
+        );
+        let over_ice = self.is_over_ice(t_dry_bulb.into());
+        let t = convert_temperature(t_dry_bulb.into(), units, UnitSystem::SI);
+        let pressure = convert_pressure(pressure.into(), units, UnitSystem::SI);
+
+        let (a, b) = if over_ice {
+            (
+                [3.64449E-04, 2.93631E-05, 4.88635E-07, 4.36543E-09],
+                [-1.07271E+01, 7.61989E-02, -1.74771E-04, 2.46721E-06],
+            )
+        } else {
+            (
+                [3.53624E-04, 2.93228E-05, 2.61474E-07, 8.57538E-09],
+                [-1.07588E+01, 6.32529E-02, -2.53591E-04, 6.33784E-07],
+            )
+        };
+        let alpha = a[0] + a[1] * t + a[2] * t.powi(2) + a[3] * t.powi(3);
+        let beta = (b[0] + b[1] * t + b[2] * t.powi(2) + b[3] * t.powi(3)).exp();
+
+        Ok(
+            (alpha * (1.0 - sat_vap_pres / pressure) + beta * (pressure / sat_vap_pres - 1.0))
+                .exp(),
+        )
+    }
+
+    // Returns the saturation vapor pressure of water vapor in moist air in Psi [IP] or Pa [SI], including
+    // the enhancement factor if enabled.
+    fn sat_vap_pres_in_moist_air(
+        &self,
+        t_dry_bulb: U::Temperature,
+        pressure: U::Pressure,
+    ) -> Result<f64, PsychroError> {
+        let sat_vap_pres: f64 = self.get_sat_vap_pres(t_dry_bulb)?.into();
+        if !self.config.enhancement_factor {
+            return Ok(sat_vap_pres);
+        }
+
+        Ok(self.get_enhancement_factor(t_dry_bulb, pressure)? * sat_vap_pres)
+    }
+
+    // Returns the dew-point temperature of water vapor at `vap_pres` in moist air at `pressure`, given
+    // `t_dew_point_at` solving for the dew point of pure water vapor. With the enhancement factor, that
+    // is solved again for the vapor pressure divided by the factor at the previous estimate, until two
+    // estimates are within the tolerance; the factor barely changes with temperature.
+    fn t_dew_point_in_moist_air<F>(
+        &self,
+        vap_pres: U::Pressure,
+        pressure: U::Pressure,
+        mut t_dew_point_at: F,
+    ) -> Result<U::Temperature, PsychroError>
+    where
+        F: FnMut(U::Pressure) -> Result<U::Temperature, PsychroError>,
+    {
+        let mut t_dew_point: f64 = t_dew_point_at(vap_pres)?.into();
+        if !self.config.enhancement_factor {
+            return Ok(t_dew_point.into_quantity());
+        }
+
+        let vap_pres: f64 = vap_pres.into();
+        for _ in 0..self.config.max_iter_count {
+            let enhancement_factor =
+                self.get_enhancement_factor(t_dew_point.into_quantity(), pressure)?;
+            let next: f64 = t_dew_point_at((vap_pres / enhancement_factor).into_quantity())?.into();
+            if (next - t_dew_point).abs() <= self.get_tolerance() || next.is_nan() {
+                return Ok(next.into_quantity());
+            }
+            t_dew_point = next;
+        }
+
+        Err(self.convergence_not_reached("get_t_dew_point_from_hum_ratio"))
+    }
+
+    /// Returns humidity ratio of saturated air in lb_H₂O lb_Air⁻¹ [IP] or kg_H₂O kg_Air⁻¹ [SI] given
+    /// dry-bulb temperature in °F [IP] or °C [SI] and pressure in Psi [IP] or Pa [SI].
+    ///
+    /// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 36, solved for W
+    pub fn get_sat_hum_ratio(
+        &self,
+        t_dry_bulb: U::Temperature,
+        pressure: U::Pressure,
+    ) -> Result<U::HumRatio, PsychroError> {
+        let sat_vapor_pres = self.sat_vap_pres_in_moist_air(t_dry_bulb, pressure)?;
+        let pressure: f64 = pressure.into();
+        let sat_hum_ratio = 0.621945 * sat_vapor_pres / (pressure - sat_vapor_pres);
+
+        // Validity check.
+        Ok(self.clamp_hum_ratio(sat_hum_ratio).into_quantity())
+    }
+
+    /// Returns saturated air enthalpy in Btu lb⁻¹ [IP] or J kg⁻¹ [SI] given dry-bulb temperature
+    /// in °F [IP] or °C [SI] and pressure in Psi [IP] or Pa [SI].
+    ///
+    /// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1
+    pub fn get_sat_air_enthalpy(
+        &self,
+        t_dry_bulb: U::Temperature,
+        pressure: U::Pressure,
+    ) -> Result<U::Enthalpy, PsychroError> {
+        let sat_hum_ratio = self.get_sat_hum_ratio(t_dry_bulb, pressure)?;
+        self.get_moist_air_enthalpy(t_dry_bulb, sat_hum_ratio)
+    }
+
+    /// Helper function returning the derivative of the natural log of the saturation vapor pressure
+    /// as a function of dry-bulb temperature in °F [IP] or °C [SI].
+    ///
+    /// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 5 & 6, or that of the saturation model
+    pub fn d_ln_pws(&self, t_dry_bulb: U::Temperature) -> f64 {
+        let t_dry_bulb: f64 = t_dry_bulb.into();
+        let over_ice = self.is_over_ice(t_dry_bulb);
+        if self.is_ip() && self.config.saturation_model == SaturationModel::Ashrae {
+            let t = get_t_rankine_from_t_fahrenheit(t_dry_bulb);
+
+            if over_ice {
+                1.0214165E+04 / t.powi(2) - 5.3765794E-03
+                    + 2.0 * 1.9202377E-07 * t
+                    + 3.0 * 3.5575832E-10 * t.powi(2)
+                    - 4.0 * 9.0344688E-14 * t.powi(3)
+                    + 4.1635019 / t
+            } else {
+                1.0440397E+04 / t.powi(2) - 2.7022355E-02 + 2.0 * 1.2890360E-05 * t
+                    - 3.0 * 2.4780681E-09 * t.powi(2)
+                    + 6.5459673 / t
+            }
+        } else {
+            // Other formulations are computed in SI, where a degree is 9/5 of a °F
+            let units = self.units.unit_system();
+            let t_dry_bulb = convert_temperature(t_dry_bulb, units, UnitSystem::SI);
+            let d_ln_pws = self
+                .config
+                .saturation_model
+                .d_ln_sat_vap_pres(t_dry_bulb, over_ice);
+
+            if self.is_ip() {
+                d_ln_pws * 5.0 / 9.0
+            } else {
+                d_ln_pws
+            }
+        }
+    }
+}
+
+impl SaturationModel {
+    // Returns the natural log of the saturation vapor pressure in Pa over ice or liquid water given dry-bulb
+    // temperature in °C.
+    fn ln_sat_vap_pres(self, t_dry_bulb: f64, over_ice: bool) -> f64 {
+        let t = get_t_kelvin_from_t_celsius(t_dry_bulb);
+
+        match (self, over_ice) {
+            (SaturationModel::Ashrae, true) => {
+                -5.6745359E+03 / t + 6.3925247 - 9.677843E-03 * t
+                    + 6.2215701E-07 * t.powi(2)
+                    + 2.0747825E-09 * t.powi(3)
+                    - 9.484024E-13 * t.powi(4)
+                    + 4.1635019 * t.ln()
+            }
+            (SaturationModel::Ashrae, false) => {
+                -5.8002206E+03 / t + 1.3914993 - 4.8640239E-02 * t + 4.1764768E-05 * t.powi(2)
+                    - 1.4452093E-08 * t.powi(3)
+                    + 6.5459673 * t.ln()
+            }
+            (SaturationModel::WagnerPruss, true) => {
+                let theta = t / 273.16;
+                611.657_f64.ln()
+                    + (-0.212144006E+02 * theta.powf(0.333333333E-02)
+                        + 0.273203819E+02 * theta.powf(0.120666667E+01)
+                        - 0.610598130E+01 * theta.powf(0.170333333E+01))
+                        / theta
+            }
+            (SaturationModel::WagnerPruss, false) => {
+                let tau = 1.0 - t / 647.096;
+                22.064E+06_f64.ln()
+                    + 647.096 / t
+                        * (-7.85951783 * tau + 1.84408259 * tau.powf(1.5)
+                            - 11.7866497 * tau.powi(3)
+                            + 22.6807411 * tau.powf(3.5)
+                            - 15.9618719 * tau.powi(4)
+                            + 1.80122502 * tau.powf(7.5))
+            }
+            (SaturationModel::MagnusTetens, true) => ln_magnus(t_dry_bulb, 611.2, 22.46, 272.62),
+            (SaturationModel::MagnusTetens, false) => ln_magnus(t_dry_bulb, 611.2, 17.62, 243.12),
+            (SaturationModel::Buck1981, true) => ln_magnus(t_dry_bulb, 611.15, 22.452, 272.55),
+            (SaturationModel::Buck1981, false) => ln_magnus(t_dry_bulb, 611.21, 17.502, 240.97),
+            (SaturationModel::Buck1996, true) => {
+                611.15_f64.ln() + (23.036 - t_dry_bulb / 333.7) * t_dry_bulb / (279.82 + t_dry_bulb)
+            }
+            (SaturationModel::Buck1996, false) => {
+                611.21_f64.ln() + (18.678 - t_dry_bulb / 234.5) * t_dry_bulb / (257.14 + t_dry_bulb)
+            }
+            (SaturationModel::GoffGratch, true) => {
+                let x = 273.16 / t;
+                (-9.09685 * (x - 1.0) - 3.56654 * x.log10() + 0.87682 * (1.0 - 1.0 / x) + 0.78614)
+                    * std::f64::consts::LN_10
+                    + 100.0_f64.ln()
+            }
+            (SaturationModel::GoffGratch, false) => {
+                let x = 273.16 / t;
+                (10.79574 * (1.0 - x) - 5.02800 * (1.0 / x).log10()
+                    + 1.50475E-04 * (1.0 - 10.0_f64.powf(-8.2969 * (1.0 / x - 1.0)))
+                    + 0.42873E-03 * (10.0_f64.powf(4.76955 * (1.0 - x)) - 1.0)
+                    + 0.78614)
+                    * std::f64::consts::LN_10
+                    + 100.0_f64.ln()
+            }
+            (SaturationModel::Sonntag, true) => {
+                -6024.5282 / t + 29.32707 + 1.0613868E-02 * t
+                    - 1.3198825E-05 * t.powi(2)
+                    - 0.49382577 * t.ln()
+            }
+            (SaturationModel::Sonntag, false) => {
+                -6096.9385 / t + 21.2409642 - 2.711193E-02 * t
+                    + 1.673952E-05 * t.powi(2)
+                    + 2.433502 * t.ln()
+            }
+        }
+    }
+
+    // Returns the derivative of ln_sat_vap_pres with respect to dry-bulb temperature in °C.
+    fn d_ln_sat_vap_pres(self, t_dry_bulb: f64, over_ice: bool) -> f64 {
+        let t = get_t_kelvin_from_t_celsius(t_dry_bulb);
+
+        match (self, over_ice) {
+            (SaturationModel::Ashrae, true) => {
+                5.6745359E+03 / t.powi(2) - 9.677843E-03
+                    + 2.0 * 6.2215701E-07 * t
+                    + 3.0 * 2.0747825E-09 * t.powi(2)
+                    - 4.0 * 9.484024E-13 * t.powi(3)
+                    + 4.1635019 / t
+            }
+            (SaturationModel::Ashrae, false) => {
+                5.8002206E+03 / t.powi(2) - 4.8640239E-02 + 2.0 * 4.1764768E-05 * t
+                    - 3.0 * 1.4452093E-08 * t.powi(2)
+                    + 6.5459673 / t
+            }
+            (SaturationModel::WagnerPruss, true) => {
+                let theta = t / 273.16;
+                (-0.212144006E+02 * (0.333333333E-02 - 1.0) * theta.powf(0.333333333E-02 - 2.0)
+                    + 0.273203819E+02 * (0.120666667E+01 - 1.0) * theta.powf(0.120666667E+01 - 2.0)
+                    - 0.610598130E+01 * (0.170333333E+01 - 1.0) * theta.powf(0.170333333E+01 - 2.0))
+                    / 273.16
+            }
+            (SaturationModel::WagnerPruss, false) => {
+                let tau = 1.0 - t / 647.096;
+                let sum = -7.85951783 * tau + 1.84408259 * tau.powf(1.5) - 11.7866497 * tau.powi(3)
+                    + 22.6807411 * tau.powf(3.5)
+                    - 15.9618719 * tau.powi(4)
+                    + 1.80122502 * tau.powf(7.5);
+                let d_sum = -7.85951783 + 1.5 * 1.84408259 * tau.sqrt()
+                    - 3.0 * 11.7866497 * tau.powi(2)
+                    + 3.5 * 22.6807411 * tau.powf(2.5)
+                    - 4.0 * 15.9618719 * tau.powi(3)
+                    + 7.5 * 1.80122502 * tau.powf(6.5);
+                -647.096 * sum / t.powi(2) - d_sum / t
+            }
+            (SaturationModel::MagnusTetens, true) => d_ln_magnus(t_dry_bulb, 22.46, 272.62),
+            (SaturationModel::MagnusTetens, false) => d_ln_magnus(t_dry_bulb, 17.62, 243.12),
+            (SaturationModel::Buck1981, true) => d_ln_magnus(t_dry_bulb, 22.452, 272.55),
+            (SaturationModel::Buck1981, false) => d_ln_magnus(t_dry_bulb, 17.502, 240.97),
+            (SaturationModel::Buck1996, true) => {
+                -t_dry_bulb / (333.7 * (279.82 + t_dry_bulb))
+                    + (23.036 - t_dry_bulb / 333.7) * 279.82 / (279.82 + t_dry_bulb).powi(2)
+            }
+            (SaturationModel::Buck1996, false) => {
+                -t_dry_bulb / (234.5 * (257.14 + t_dry_bulb))
+                    + (18.678 - t_dry_bulb / 234.5) * 257.14 / (257.14 + t_dry_bulb).powi(2)
+            }
+            (SaturationModel::GoffGratch, true) => {
+                let x = 273.16 / t;
+                (9.09685 * x / t + 3.56654 / (t * std::f64::consts::LN_10) - 0.87682 / 273.16)
+                    * std::f64::consts::LN_10
+            }
+            (SaturationModel::GoffGratch, false) => {
+                let x = 273.16 / t;
+                (10.79574 * x / t - 5.02800 / (t * std::f64::consts::LN_10)
+                    + 1.50475E-04
+                        * 10.0_f64.powf(-8.2969 * (1.0 / x - 1.0))
+                        * std::f64::consts::LN_10
+                        * 8.2969
+                        / 273.16
+                    + 0.42873E-03
+                        * 10.0_f64.powf(4.76955 * (1.0 - x))
+                        * std::f64::consts::LN_10
+                        * 4.76955
+                        * x
+                        / t)
+                    * std::f64::consts::LN_10
+            }
+            (SaturationModel::Sonntag, true) => {
+                6024.5282 / t.powi(2) + 1.0613868E-02 - 2.0 * 1.3198825E-05 * t - 0.49382577 / t
+            }
+            (SaturationModel::Sonntag, false) => {
+                6096.9385 / t.powi(2) - 2.711193E-02 + 2.0 * 1.673952E-05 * t + 2.433502 / t
+            }
+        }
+    }
+}
+
+// Returns the natural log of a saturation vapor pressure in Pa given by a Magnus formula
+// c exp(a t / (b + t)) at dry-bulb temperature t in °C.
+fn ln_magnus(t_dry_bulb: f64, c: f64, a: f64, b: f64) -> f64 {
+    c.ln() + a * t_dry_bulb / (b + t_dry_bulb)
+}
+
+// Returns the derivative of ln_magnus with respect to dry-bulb temperature in °C.
+fn d_ln_magnus(t_dry_bulb: f64, a: f64, b: f64) -> f64 {
+    a * b / (b + t_dry_bulb).powi(2)
+}
+
+/******************************************************************************************************
+ * Moist Air Calculations
+ *****************************************************************************************************/
+
+impl<U: Units> Psychrolib<U> {
+    /// Returns vapor pressure deficit in Psi [IP] or Pa [SI] given dry-bulb temperature in °F [IP] or °C [SI],
+    /// humidity ratio in lb_H₂O lb_Air⁻¹ [IP] or kg_H₂O kg_Air⁻¹ [SI], and pressure in Psi [IP] or Pa [SI].
+    ///
+    /// Reference: Oke (1987) eqn 2.13a
+    pub fn get_vapor_pressure_deficit(
+        &self,
+        t_dry_bulb: U::Temperature,
+        hum_ratio: U::HumRatio,
+        pressure: U::Pressure,
+    ) -> Result<U::Pressure, PsychroError> {
+        let rel_hum: f64 = self
+            .get_rel_hum_from_hum_ratio(t_dry_bulb, hum_ratio, pressure)?
+            .into();
+        let sat_vap_pres = self.sat_vap_pres_in_moist_air(t_dry_bulb, pressure)?;
+        Ok((sat_vap_pres * (1.0 - rel_hum)).into_quantity())
+    }
+
+    /// Returns the degree of saturation (i.e humidity ratio of the air / humidity ratio of the air at saturation
+    /// at the same temperature and pressure) given dry-bulb temperature in °F [IP] or °C [SI], humidity ratio
+    /// in lb_H₂O lb_Air⁻¹ [IP] or kg_H₂O kg_Air⁻¹ [SI], and atmospheric pressure in Psi [IP] or Pa [SI].
+    ///
+    /// Reference: ASHRAE Handbook - Fundamentals (2009) ch. 1 eqn 12
+    ///
+    /// This definition is absent from the 2017 Handbook. Using 2009 version instead.
+    pub fn get_degree_of_saturation(
+        &self,
+        t_dry_bulb: U::Temperature,
+        hum_ratio: U::HumRatio,
+        pressure: U::Pressure,
+    ) -> Result<f64, PsychroError> {
+        let bounded_hum_ratio = self.bounded_hum_ratio(hum_ratio)?;
+
+        let sat_hum_ratio: f64 = self.get_sat_hum_ratio(t_dry_bulb, pressure)?.into();
+        Ok(bounded_hum_ratio / sat_hum_ratio)
+    }
+
+    /// Returns moist air enthalpy in Btu lb⁻¹ [IP] or J kg⁻¹ [SI] given dry-bulb temperature in °F [IP] or °C [SI]
+    /// and humidity ratio in lb_H₂O lb_Air⁻¹ [IP] or kg_H₂O kg_Air⁻¹ [SI].
+    ///
+    /// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 30
+    pub fn get_moist_air_enthalpy(
+        &self,
+        t_dry_bulb: U::Temperature,
+        hum_ratio: U::HumRatio,
+    ) -> Result<U::Enthalpy, PsychroError> {
+        let bounded_hum_ratio = self.bounded_hum_ratio(hum_ratio)?;
+        let t_dry_bulb: f64 = t_dry_bulb.into();
+
+        let moist_air_enthalpy = if self.is_ip() {
+            0.240 * t_dry_bulb + bounded_hum_ratio * (1061.0 + 0.444 * t_dry_bulb)
+        } else {
+            (1.006 * t_dry_bulb + bounded_hum_ratio * (2501.0 + 1.86 * t_dry_bulb)) * 1000.0
+        };
+
+        Ok(moist_air_enthalpy.into_quantity())
+    }
+
+    /// Returns moist air specific volume in ft³ lb⁻¹ of dry air [IP] or in m³ kg⁻¹ of dry air [SI] given
+    /// dry-bulb temperature in °F [IP] or °C [SI], humidity ratio in lb_H₂O lb_Air⁻¹ [IP] or kg_H₂O kg_Air⁻¹ [SI],
+    /// and pressure in Psi [IP] or Pa [SI].
+    ///
+    /// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 26
+    ///
+    /// In IP units, R_DA_IP / 144 equals 0.370486 which is the coefficient appearing in eqn 26
+    /// The factor 144 is for the conversion of Psi = lb in⁻² to lb ft⁻².
+    pub fn get_moist_air_volume(
+        &self,
+        t_dry_bulb: U::Temperature,
+        hum_ratio: U::HumRatio,
+        pressure: U::Pressure,
+    ) -> Result<f64, PsychroError> {
+        let bounded_hum_ratio = self.bounded_hum_ratio(hum_ratio)?;
+        let t_dry_bulb: f64 = t_dry_bulb.into();
+        let pressure: f64 = pressure.into();
+
+        if self.is_ip() {
+            Ok(R_DA_IP
+                * get_t_rankine_from_t_fahrenheit(t_dry_bulb)
+                * (1.0 + 1.607858 * bounded_hum_ratio)
+                / (144.0 * pressure))
+        } else {
+            Ok(R_DA_SI
+                * get_t_kelvin_from_t_celsius(t_dry_bulb)
+                * (1.0 + 1.607858 * bounded_hum_ratio)
+                / pressure)
+        }
+    }
+
+    /// Returns dry-bulb temperature in °F [IP] or °C [SI] given moist air specific volume in ft³ lb⁻¹ of dry air [IP]
+    /// or in m³ kg⁻¹ of dry air [SI], humidity ratio in lb_H₂O lb_Air⁻¹ [IP] or kg_H₂O kg_Air⁻¹ [SI], and pressure
+    /// in Psi [IP] or Pa [SI].
+    ///
+    /// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 26
+    ///
+    /// In IP units, R_DA_IP / 144 equals 0.370486 which is the coefficient appearing in eqn 26
+    /// The factor 144 is for the conversion of Psi = lb in⁻² to lb ft⁻².
+    /// Based on the get_moist_air_volume function, rearranged for dry-bulb temperature.
+    pub fn get_t_dry_bulb_from_moist_air_volume_and_hum_ratio(
+        &self,
+        moist_air_volume: f64,
+        hum_ratio: U::HumRatio,
+        pressure: U::Pressure,
+    ) -> Result<U::Temperature, PsychroError> {
+        let bounded_hum_ratio = self.bounded_hum_ratio(hum_ratio)?;
+        let pressure: f64 = pressure.into();
+
+        let t_dry_bulb = if self.is_ip() {
+            get_t_fahrenheit_from_t_rankine(
+                moist_air_volume * (144.0 * pressure)
+                    / (R_DA_IP * (1.0 + 1.607858 * bounded_hum_ratio)),
+            )
+        } else {
+            get_t_celsius_from_t_kelvin(
+                moist_air_volume * pressure / (R_DA_SI * (1.0 + 1.607858 * bounded_hum_ratio)),
+            )
+        };
+
+        Ok(t_dry_bulb.into_quantity())
+    }
+
+    /// Returns moist air density in lb ft⁻³ [IP] or kg m⁻³ [SI] given dry-bulb temperature in °F [IP] or °C [SI],
+    /// humidity ratio in lb_H₂O lb_Air⁻¹ [IP] or kg_H₂O kg_Air⁻¹ [SI], and pressure in Psi [IP] or Pa [SI].
+    ///
+    /// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 11
+    pub fn get_moist_air_density(
+        &self,
+        t_dry_bulb: U::Temperature,
+        hum_ratio: U::HumRatio,
+        pressure: U::Pressure,
+    ) -> Result<f64, PsychroError> {
+        let bounded_hum_ratio = self.bounded_hum_ratio(hum_ratio)?;
+
+        let moist_air_volume = self.get_moist_air_volume(t_dry_bulb, hum_ratio, pressure)?;
+        Ok((1.0 + bounded_hum_ratio) / moist_air_volume)
+    }
+}
+
+/******************************************************************************************************
+ * Standard atmosphere
+ *****************************************************************************************************/
+
+impl<U: Units> Psychrolib<U> {
+    /// Returns standard atmosphere barometric pressure in Psi [IP] or Pa [SI], given the elevation (altitude)
+    /// in ft [IP] or m [SI].
+    ///
+    /// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 3
+    ///
+    /// # Example
+    ///     use psychrolib::{Psychrolib, UnitSystem};
+    ///
+    ///     let psych = Psychrolib::new(UnitSystem::SI);
+    ///     let pressure = psych.get_standard_atm_pressure(1600.0);
+    ///
+    ///     assert!((pressure - 83523.0).abs() < 1.0);
+    pub fn get_standard_atm_pressure(&self, altitude: f64) -> U::Pressure {
+        let pressure = if self.is_ip() {
+            14.696 * (1.0 - 6.8754e-06 * altitude).powf(5.2559)
+        } else {
+            101325.0 * (1.0 - 2.25577e-05 * altitude).powf(5.2559)
+        };
+
+        pressure.into_quantity()
+    }
+
+    /// Returns standard atmosphere temperature in °F [IP] or °C [SI], given the elevation (altitude)
+    /// in ft [IP] or m [SI].
+    ///
+    /// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 4
+    pub fn get_standard_atm_temperature(&self, altitude: f64) -> U::Temperature {
+        let temperature = if self.is_ip() {
+            59.0 - 0.00356620 * altitude
+        } else {
+            15.0 - 0.0065 * altitude
+        };
+
+        temperature.into_quantity()
+    }
+
+    /// Returns sea level pressure in Psi [IP] or Pa [SI] given observed station pressure in Psi [IP] or Pa [SI],
+    /// altitude above sea level in ft [IP] or m [SI], and dry-bulb temperature in °F [IP] or °C [SI].
+    ///
+    /// Reference: Hess SL, Introduction to theoretical meteorology, Holt Rinehart and Winston, NY 1959,
+    /// ch. 6.5; Stull RB, Meteorology for scientists and engineers, 2nd edition,
+    /// Brooks/Cole 2000, ch. 1.
+    ///
+    /// The standard procedure for the US is to use for t_dry_bulb the average
+    /// of the current station temperature and the station temperature from 12 hours ago.
+    pub fn get_sea_level_pressure(
+        &self,
+        station_pressure: U::Pressure,
+        altitude: f64,
+        t_dry_bulb: U::Temperature,
+    ) -> U::Pressure {
+        let station_pressure: f64 = station_pressure.into();
+        let t_dry_bulb: f64 = t_dry_bulb.into();
+        let h = if self.is_ip() {
+            // Calculate average temperature in column of air, assuming a lapse rate
+            // of 3.6 °F/1000ft
+            let t_column = t_dry_bulb + 0.0036 * altitude / 2.0;
+
+            // Determine the scale height
+            53.351 * get_t_rankine_from_t_fahrenheit(t_column)
+        } else {
+            // Calculate average temperature in column of air, assuming a lapse rate
+            // of 6.5 °C/km
+            let t_column = t_dry_bulb + 0.0065 * altitude / 2.0;
+
+            // Determine the scale height
+            287.055 * get_t_kelvin_from_t_celsius(t_column) / 9.807
+        };
+
+        // Calculate the sea level pressure
+        (station_pressure * (altitude / h).exp()).into_quantity()
+    }
+
+    /// Returns station pressure in Psi [IP] or Pa [SI] from sea level pressure in Psi [IP] or Pa [SI],
+    /// altitude above sea level in ft [IP] or m [SI], and dry-bulb temperature in °F [IP] or °C [SI].
+    ///
+    /// Reference: See get_sea_level_pressure
+    ///
+    /// This function is just the inverse of get_sea_level_pressure.
+    pub fn get_station_pressure(
+        &self,
+        sea_level_pressure: U::Pressure,
+        altitude: f64,
+        t_dry_bulb: U::Temperature,
+    ) -> U::Pressure {
+        let sea_level_pressure: f64 = sea_level_pressure.into();
+        let scale: f64 = self
+            .get_sea_level_pressure(1.0.into_quantity(), altitude, t_dry_bulb)
+            .into();
+        (sea_level_pressure / scale).into_quantity()
+    }
+}
+
+/******************************************************************************************************
+ * Functions to set all psychrometric values
+ *****************************************************************************************************/
+
+impl<U: Units> Psychrolib<U> {
+    /// Utility function to calculate humidity ratio, dew-point temperature, relative humidity,
+    /// vapour pressure, moist air enthalpy, moist air volume, and degree of saturation of air given
+    /// dry-bulb temperature in °F [IP] or °C [SI], wet-bulb temperature in °F [IP] or °C [SI],
+    /// and pressure in Psi [IP] or Pa [SI].
+    ///
+    /// # Example
+    ///     use psychrolib::{Psychrolib, UnitSystem};
+    ///
+    ///     let psych = Psychrolib::new(UnitSystem::SI);
+    ///     let state = psych.calc_psychrometrics_from_t_wet_bulb(40.0, 20.0, 101325.0).unwrap();
+    ///
+    ///     assert!((state.hum_ratio - 0.0065).abs() < 0.0001);
+    ///     assert!((state.rel_hum - 0.14).abs() < 0.01);
+    pub fn calc_psychrometrics_from_t_wet_bulb(
+        &self,
+        t_dry_bulb: U::Temperature,
+        t_wet_bulb: U::Temperature,
+        pressure: U::Pressure,
+    ) -> Result<MoistAirState<U>, PsychroError> {
+        // The state holds the wet-bulb temperature as checked, e.g. clamped to the dry-bulb temperature
+        let t_wet_bulb = self.check_below_t_dry_bulb(Property::TWetBulb, t_wet_bulb, t_dry_bulb)?;
+        let hum_ratio = self.get_hum_ratio_from_t_wet_bulb(t_dry_bulb, t_wet_bulb, pressure)?;
+        let t_dew_point = self.get_t_dew_point_from_hum_ratio(t_dry_bulb, hum_ratio, pressure)?;
+        let rel_hum = self.get_rel_hum_from_hum_ratio(t_dry_bulb, hum_ratio, pressure)?;
+
+        self.calc_moist_air_state(
+            t_dry_bulb,
+            t_wet_bulb,
+            t_dew_point,
+            rel_hum,
+            hum_ratio,
+            pressure,
+        )
+    }
+
+    /// Utility function to calculate humidity ratio, wet-bulb temperature, relative humidity,
+    /// vapour pressure, moist air enthalpy, moist air volume, and degree of saturation of air given
+    /// dry-bulb temperature in °F [IP] or °C [SI], dew-point temperature in °F [IP] or °C [SI],
+    /// and pressure in Psi [IP] or Pa [SI].
+    pub fn calc_psychrometrics_from_t_dew_point(
+        &self,
+        t_dry_bulb: U::Temperature,
+        t_dew_point: U::Temperature,
+        pressure: U::Pressure,
+    ) -> Result<MoistAirState<U>, PsychroError> {
+        let hum_ratio = self.get_hum_ratio_from_t_dew_point(t_dew_point, pressure)?;
+        let t_wet_bulb = self.get_t_wet_bulb_from_hum_ratio(t_dry_bulb, hum_ratio, pressure)?;
+        let rel_hum = self.get_rel_hum_from_hum_ratio(t_dry_bulb, hum_ratio, pressure)?;
+
+        self.calc_moist_air_state(
+            t_dry_bulb,
+            t_wet_bulb,
+            t_dew_point,
+            rel_hum,
+            hum_ratio,
+            pressure,
+        )
+    }
+
+    /// Utility function to calculate humidity ratio, wet-bulb temperature, dew-point temperature,
+    /// vapour pressure, moist air enthalpy, moist air volume, and degree of saturation of air given
+    /// dry-bulb temperature in °F [IP] or °C [SI], relative humidity in range [0, 1],
+    /// and pressure in Psi [IP] or Pa [SI].
+    pub fn calc_psychrometrics_from_rel_hum(
+        &self,
+        t_dry_bulb: U::Temperature,
+        rel_hum: U::RelHum,
+        pressure: U::Pressure,
+    ) -> Result<MoistAirState<U>, PsychroError> {
+        // The state holds the relative humidity as checked, e.g. clamped to 1
+        let rel_hum = self.check_rel_hum(rel_hum)?.into_quantity();
+        let hum_ratio = self.get_hum_ratio_from_rel_hum(t_dry_bulb, rel_hum, pressure)?;
+        let t_wet_bulb = self.get_t_wet_bulb_from_hum_ratio(t_dry_bulb, hum_ratio, pressure)?;
+        let t_dew_point = self.get_t_dew_point_from_hum_ratio(t_dry_bulb, hum_ratio, pressure)?;
+
+        self.calc_moist_air_state(
+            t_dry_bulb,
+            t_wet_bulb,
+            t_dew_point,
+            rel_hum,
+            hum_ratio,
+            pressure,
+        )
+    }
+
+    // Completes a MoistAirState with the properties common to all calc_psychrometrics_* functions.
+    fn calc_moist_air_state(
+        &self,
+        t_dry_bulb: U::Temperature,
+        t_wet_bulb: U::Temperature,
+        t_dew_point: U::Temperature,
+        rel_hum: U::RelHum,
+        hum_ratio: U::HumRatio,
+        pressure: U::Pressure,
+    ) -> Result<MoistAirState<U>, PsychroError> {
+        Ok(MoistAirState {
+            t_dry_bulb,
+            t_wet_bulb,
+            t_dew_point,
+            rel_hum,
+            hum_ratio,
+            vap_pres: self.get_vap_pres_from_hum_ratio(hum_ratio, pressure)?,
+            moist_air_enthalpy: self.get_moist_air_enthalpy(t_dry_bulb, hum_ratio)?,
+            moist_air_volume: self.get_moist_air_volume(t_dry_bulb, hum_ratio, pressure)?,
+            degree_of_saturation: self.get_degree_of_saturation(t_dry_bulb, hum_ratio, pressure)?,
+            pressure,
+            units: self.units,
+        })
+    }
+}
+
+/******************************************************************************************************
+ * Moist air state from any pair of independent properties
+ *****************************************************************************************************/
+
+// Smallest relative change of the given property over one tolerance step of the dry-bulb temperature
+// for a pair to fix a state: below it, round-off on the property would move the state beyond the tolerance.
+const MIN_PAIR_SENSITIVITY: f64 = 1e-8;
+
+/// PropertyPair is a pair of independent properties fixing the state of moist air at a given pressure,
+/// as given to Psychrolib::state_from. Temperatures are in °F [IP] or °C [SI], moist air enthalpies in
+/// Btu lb⁻¹ [IP] or J kg⁻¹ [SI], humidity ratios in lb_H₂O lb_Air⁻¹ [IP] or kg_H₂O kg_Air⁻¹ [SI],
+/// relative humidities in range [0, 1] and specific volumes in ft³ lb⁻¹ [IP] or m³ kg⁻¹ [SI].
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum PropertyPair<U: Units = UnitSystem> {
+    /// Dry-bulb and wet-bulb temperatures
+    TDryBulbTWetBulb(U::Temperature, U::Temperature),
+    /// Dry-bulb and dew-point temperatures
+    TDryBulbTDewPoint(U::Temperature, U::Temperature),
+    /// Dry-bulb temperature and relative humidity
+    TDryBulbRelHum(U::Temperature, U::RelHum),
+    /// Dry-bulb temperature and humidity ratio
+    TDryBulbHumRatio(U::Temperature, U::HumRatio),
+    /// Dry-bulb temperature and moist air enthalpy
+    TDryBulbEnthalpy(U::Temperature, U::Enthalpy),
+    /// Moist air enthalpy and humidity ratio
+    EnthalpyHumRatio(U::Enthalpy, U::HumRatio),
+    /// Moist air enthalpy and relative humidity
+    EnthalpyRelHum(U::Enthalpy, U::RelHum),
+    /// Moist air enthalpy and wet-bulb temperature
+    EnthalpyTWetBulb(U::Enthalpy, U::Temperature),
+    /// Dew-point and wet-bulb temperatures
+    TDewPointTWetBulb(U::Temperature, U::Temperature),
+    /// Dew-point temperature and relative humidity
+    TDewPointRelHum(U::Temperature, U::RelHum),
+    /// Wet-bulb temperature and relative humidity
+    TWetBulbRelHum(U::Temperature, U::RelHum),
+    /// Wet-bulb temperature and humidity ratio
+    TWetBulbHumRatio(U::Temperature, U::HumRatio),
+    /// Relative humidity and humidity ratio
+    RelHumHumRatio(U::RelHum, U::HumRatio),
+    /// Moist air specific volume and relative humidity
+    MoistAirVolumeRelHum(f64, U::RelHum),
+    /// Moist air specific volume and humidity ratio
+    MoistAirVolumeHumRatio(f64, U::HumRatio),
+}
+
+impl<U: Units> PropertyPair<U> {
+    fn description(&self) -> &'static str {
+        match self {
+            PropertyPair::TDryBulbTWetBulb(..) => "Dry bulb and wet bulb temperatures",
+            PropertyPair::TDryBulbTDewPoint(..) => "Dry bulb and dew point temperatures",
+            PropertyPair::TDryBulbRelHum(..) => "Dry bulb temperature and relative humidity",
+            PropertyPair::TDryBulbHumRatio(..) => "Dry bulb temperature and humidity ratio",
+            PropertyPair::TDryBulbEnthalpy(..) => "Dry bulb temperature and enthalpy",
+            PropertyPair::EnthalpyHumRatio(..) => "Enthalpy and humidity ratio",
+            PropertyPair::EnthalpyRelHum(..) => "Enthalpy and relative humidity",
+            PropertyPair::EnthalpyTWetBulb(..) => "Enthalpy and wet bulb temperature",
+            PropertyPair::TDewPointTWetBulb(..) => "Dew point and wet bulb temperatures",
+            PropertyPair::TDewPointRelHum(..) => "Dew point temperature and relative humidity",
+            PropertyPair::TWetBulbRelHum(..) => "Wet bulb temperature and relative humidity",
+            PropertyPair::TWetBulbHumRatio(..) => "Wet bulb temperature and humidity ratio",
+            PropertyPair::RelHumHumRatio(..) => "Relative humidity and humidity ratio",
+            PropertyPair::MoistAirVolumeRelHum(..) => "Specific volume and relative humidity",
+            PropertyPair::MoistAirVolumeHumRatio(..) => "Specific volume and humidity ratio",
+        }
+    }
+}
+
+impl<U: Units> Psychrolib<U> {
+    /// Utility function to calculate every psychrometric property of moist air given any pair of
+    /// independent properties (see PropertyPair) and pressure in Psi [IP] or Pa [SI].
+    ///
+    /// Pairs without the dry-bulb temperature are solved for it with Brent's method, within the tolerance
+    /// of the Psychrolib. A PsychroError::DegeneratePair is returned when the pair has no solution in the
+    /// range of validity of the equations, e.g. an enthalpy above that of saturated air at the wet-bulb
+    /// temperature, or when the given property barely changes with the dry-bulb temperature at the solution,
+    /// e.g. enthalpy with a wet-bulb temperature at the freezing point.
+    ///
+    /// # Example
+    ///     use psychrolib::{PropertyPair, Psychrolib, UnitSystem};
+    ///
+    ///     let psych = Psychrolib::new(UnitSystem::SI);
+    ///     let state = psych
+    ///         .state_from(PropertyPair::EnthalpyRelHum(50000.0, 0.5), 101325.0)
+    ///         .unwrap();
+    ///
+    ///     assert!((state.moist_air_enthalpy - 50000.0).abs() < 10.0);
+    ///     assert!((state.t_dry_bulb - 24.8).abs() < 0.1);
+    pub fn state_from(
+        &self,
+        pair: PropertyPair<U>,
+        pressure: U::Pressure,
+    ) -> Result<MoistAirState<U>, PsychroError> {
+        let (t_dry_bulb, hum_ratio): (f64, f64) = match pair {
+            PropertyPair::TDryBulbTWetBulb(t_dry_bulb, t_wet_bulb) => {
+                return self.calc_psychrometrics_from_t_wet_bulb(t_dry_bulb, t_wet_bulb, pressure);
+            }
+            PropertyPair::TDryBulbTDewPoint(t_dry_bulb, t_dew_point) => {
+                return self.calc_psychrometrics_from_t_dew_point(
+                    t_dry_bulb,
+                    t_dew_point,
+                    pressure,
+                );
+            }
+            PropertyPair::TDryBulbRelHum(t_dry_bulb, rel_hum) => {
+                return self.calc_psychrometrics_from_rel_hum(t_dry_bulb, rel_hum, pressure);
+            }
+            PropertyPair::TDryBulbHumRatio(t_dry_bulb, hum_ratio) => {
+                (t_dry_bulb.into(), hum_ratio.into())
+            }
+            PropertyPair::TDryBulbEnthalpy(t_dry_bulb, moist_air_enthalpy) => {
+                let hum_ratio =
+                    self.get_hum_ratio_from_enthalpy_and_t_dry_bulb(moist_air_enthalpy, t_dry_bulb);
+                (t_dry_bulb.into(), hum_ratio.into())
+            }
+            PropertyPair::EnthalpyHumRatio(moist_air_enthalpy, hum_ratio) => {
+                let t_dry_bulb =
+                    self.get_t_dry_bulb_from_enthalpy_and_hum_ratio(moist_air_enthalpy, hum_ratio)?;
+                (t_dry_bulb.into(), hum_ratio.into())
+            }
+            PropertyPair::MoistAirVolumeHumRatio(moist_air_volume, hum_ratio) => {
+                let t_dry_bulb = self.get_t_dry_bulb_from_moist_air_volume_and_hum_ratio(
+                    moist_air_volume,
+                    hum_ratio,
+                    pressure,
+                )?;
+                (t_dry_bulb.into(), hum_ratio.into())
+            }
+            PropertyPair::EnthalpyRelHum(moist_air_enthalpy, rel_hum) => {
+                let hum_ratio_at =
+                    |t: f64| self.get_hum_ratio_from_rel_hum(t.into_quantity(), rel_hum, pressure);
+                let t_dry_bulb = self.solve_t_dry_bulb(
+                    &pair,
+                    moist_air_enthalpy.into(),
+                    self.t_dry_bulb_bounds_at_rel_hum(rel_hum, pressure)?,
+                    |t| {
+                        Ok(self
+                            .get_moist_air_enthalpy(t.into_quantity(), hum_ratio_at(t)?)?
+                            .into())
+                    },
+                )?;
+                (t_dry_bulb, hum_ratio_at(t_dry_bulb)?.into())
+            }
+            PropertyPair::MoistAirVolumeRelHum(moist_air_volume, rel_hum) => {
+                let hum_ratio_at =
+                    |t: f64| self.get_hum_ratio_from_rel_hum(t.into_quantity(), rel_hum, pressure);
+                let t_dry_bulb = self.solve_t_dry_bulb(
+                    &pair,
+                    moist_air_volume,
+                    self.t_dry_bulb_bounds_at_rel_hum(rel_hum, pressure)?,
+                    |t| self.get_moist_air_volume(t.into_quantity(), hum_ratio_at(t)?, pressure),
+                )?;
+                (t_dry_bulb, hum_ratio_at(t_dry_bulb)?.into())
+            }
+            PropertyPair::EnthalpyTWetBulb(moist_air_enthalpy, t_wet_bulb) => {
+                let hum_ratio_at = |t: f64| {
+                    self.get_hum_ratio_from_t_wet_bulb(t.into_quantity(), t_wet_bulb, pressure)
+                };
+                let t_dry_bulb = self.solve_t_dry_bulb(
+                    &pair,
+                    moist_air_enthalpy.into(),
+                    self.t_dry_bulb_bounds_at_t_wet_bulb(t_wet_bulb, pressure)?,
+                    |t| {
+                        Ok(self
+                            .get_moist_air_enthalpy(t.into_quantity(), hum_ratio_at(t)?)?
+                            .into())
+                    },
+                )?;
+                (t_dry_bulb, hum_ratio_at(t_dry_bulb)?.into())
+            }
+            PropertyPair::TWetBulbRelHum(t_wet_bulb, rel_hum) => {
+                let hum_ratio_at = |t: f64| {
+                    self.get_hum_ratio_from_t_wet_bulb(t.into_quantity(), t_wet_bulb, pressure)
+                };
+                let t_dry_bulb = self.solve_t_dry_bulb(
+                    &pair,
+                    self.check_rel_hum(rel_hum)?,
+                    self.t_dry_bulb_bounds_at_t_wet_bulb(t_wet_bulb, pressure)?,
+                    |t| {
+                        Ok(self
+                            .get_rel_hum_from_hum_ratio(
+                                t.into_quantity(),
+                                hum_ratio_at(t)?,
+                                pressure,
+                            )?
+                            .into())
+                    },
+                )?;
+                (t_dry_bulb, hum_ratio_at(t_dry_bulb)?.into())
+            }
+            PropertyPair::TDewPointTWetBulb(t_dew_point, t_wet_bulb) => {
+                let hum_ratio = self.get_hum_ratio_from_t_dew_point(t_dew_point, pressure)?;
+                let t_dry_bulb = self.t_dry_bulb_from_t_wet_bulb_and_hum_ratio(
+                    &pair, t_wet_bulb, hum_ratio, pressure,
+                )?;
+                (t_dry_bulb, hum_ratio.into())
+            }
+            PropertyPair::TWetBulbHumRatio(t_wet_bulb, hum_ratio) => {
+                let t_dry_bulb = self.t_dry_bulb_from_t_wet_bulb_and_hum_ratio(
+                    &pair, t_wet_bulb, hum_ratio, pressure,
+                )?;
+                (t_dry_bulb, hum_ratio.into())
+            }
+            PropertyPair::TDewPointRelHum(t_dew_point, rel_hum) => {
+                let vap_pres = self
+                    .sat_vap_pres_in_moist_air(t_dew_point, pressure)?
+                    .into_quantity();
+                let t_dry_bulb =
+                    self.t_dry_bulb_from_vap_pres_and_rel_hum(&pair, vap_pres, rel_hum, pressure)?;
+                let hum_ratio = self.get_hum_ratio_from_vap_pres(vap_pres, pressure)?;
+                (t_dry_bulb, hum_ratio.into())
+            }
+            PropertyPair::RelHumHumRatio(rel_hum, hum_ratio) => {
+                let vap_pres = self.get_vap_pres_from_hum_ratio(hum_ratio, pressure)?;
+                let t_dry_bulb =
+                    self.t_dry_bulb_from_vap_pres_and_rel_hum(&pair, vap_pres, rel_hum, pressure)?;
+                (t_dry_bulb, hum_ratio.into())
+            }
+        };
+
+        let t_dry_bulb = t_dry_bulb.into_quantity();
+        let hum_ratio = hum_ratio.into_quantity();
+        let t_wet_bulb = self.get_t_wet_bulb_from_hum_ratio(t_dry_bulb, hum_ratio, pressure)?;
+        let t_dew_point = self.get_t_dew_point_from_hum_ratio(t_dry_bulb, hum_ratio, pressure)?;
+        let rel_hum = self.get_rel_hum_from_hum_ratio(t_dry_bulb, hum_ratio, pressure)?;
+
+        self.calc_moist_air_state(
+            t_dry_bulb,
+            t_wet_bulb,
+            t_dew_point,
+            rel_hum,
+            hum_ratio,
+            pressure,
+        )
+    }
+
+    // Solves for the dry-bulb temperature within `bounds` at which `property_at` equals `property`, and checks
+    // that `property` is sensitive enough to the dry-bulb temperature to fix it within the tolerance.
+    fn solve_t_dry_bulb<F>(
+        &self,
+        pair: &PropertyPair<U>,
+        property: f64,
+        bounds: (f64, f64),
+        property_at: F,
+    ) -> Result<f64, PsychroError>
+    where
+        F: Fn(f64) -> Result<f64, PsychroError>,
+    {
+        let degenerate = PsychroError::DegeneratePair {
+            pair: pair.description(),
+        };
+        let (lower, upper) = bounds;
+        let t_dry_bulb = match solve::brent(self, |t| Ok(property_at(t)? - property), lower, upper)
+        {
+            Err(PsychroError::RootNotBracketed { .. }) => return Err(degenerate),
+            result => result?,
+        };
+        if t_dry_bulb.is_nan() {
+            return Ok(t_dry_bulb);
+        }
+
+        let step_lower = (t_dry_bulb - self.get_tolerance()).max(lower);
+        let step_upper = (t_dry_bulb + self.get_tolerance()).min(upper);
+        let change = (property_at(step_upper)? - property_at(step_lower)?).abs();
+        if change <= MIN_PAIR_SENSITIVITY * property.abs() {
+            return Err(degenerate);
+        }
+
+        Ok(t_dry_bulb)
+    }
+
+    // Returns the range of dry-bulb temperatures over which the saturation vapor pressure equations are
+    // valid and air at a relative humidity `rel_hum` has a vapor pressure below 99 % of the pressure.
+    fn t_dry_bulb_bounds_at_rel_hum(
+        &self,
+        rel_hum: U::RelHum,
+        pressure: U::Pressure,
+    ) -> Result<(f64, f64), PsychroError> {
+        let (t_min, t_max) = self.units.t_dry_bulb_bounds();
+        let max_sat_vap_pres = 0.99 * pressure.into() / self.check_rel_hum(rel_hum)?;
+        if max_sat_vap_pres >= self.get_sat_vap_pres(t_max.into_quantity())?.into() {
+            return Ok((t_min, t_max));
+        }
+
+        let t_upper = self.get_t_dew_point_from_vap_pres(
+            t_max.into_quantity(),
+            max_sat_vap_pres.into_quantity(),
+        )?;
+        Ok((t_min, t_upper.into()))
+    }
+
+    // Returns the range of dry-bulb temperatures from the wet-bulb temperature up to that of air at the
+    // wet-bulb temperature with the minimum humidity ratio, capped by the range of validity of the equations.
+    fn t_dry_bulb_bounds_at_t_wet_bulb(
+        &self,
+        t_wet_bulb: U::Temperature,
+        pressure: U::Pressure,
+    ) -> Result<(f64, f64), PsychroError> {
+        let (_, t_max) = self.units.t_dry_bulb_bounds();
+        let t_lower: f64 = t_wet_bulb.into();
+        let excess_hum_ratio_at = |t: f64| {
+            Ok(
+                self.unbounded_hum_ratio_from_t_wet_bulb(t.into_quantity(), t_wet_bulb, pressure)?
+                    - self.config.min_hum_ratio,
+            )
+        };
+        if excess_hum_ratio_at(t_max)? >= 0.0 {
+            return Ok((t_lower, t_max));
+        }
+
+        // Stay one tolerance step on the side of the valid humidity ratios
+        let t_upper =
+            solve::brent(self, excess_hum_ratio_at, t_lower, t_max)? - self.get_tolerance();
+        Ok((t_lower, t_upper.max(t_lower)))
+    }
+
+    // Returns the dry-bulb temperature of air with the humidity ratio `hum_ratio` and the wet-bulb temperature
+    // `t_wet_bulb`. The humidity ratio decreases as the dry-bulb temperature rises above the wet-bulb temperature.
+    fn t_dry_bulb_from_t_wet_bulb_and_hum_ratio(
+        &self,
+        pair: &PropertyPair<U>,
+        t_wet_bulb: U::Temperature,
+        hum_ratio: U::HumRatio,
+        pressure: U::Pressure,
+    ) -> Result<f64, PsychroError> {
+        self.solve_t_dry_bulb(
+            pair,
+            self.bounded_hum_ratio(hum_ratio)?,
+            self.t_dry_bulb_bounds_at_t_wet_bulb(t_wet_bulb, pressure)?,
+            |t| {
+                Ok(self
+                    .get_hum_ratio_from_t_wet_bulb(t.into_quantity(), t_wet_bulb, pressure)?
+                    .into())
+            },
+        )
+    }
+
+    // Returns the dry-bulb temperature at which the vapor pressure `vap_pres` is a fraction `rel_hum` of the
+    // saturation vapor pressure, i.e. the dew point of the vapor pressure `vap_pres` / `rel_hum`.
+    fn t_dry_bulb_from_vap_pres_and_rel_hum(
+        &self,
+        pair: &PropertyPair<U>,
+        vap_pres: U::Pressure,
+        rel_hum: U::RelHum,
+        pressure: U::Pressure,
+    ) -> Result<f64, PsychroError> {
+        let rel_hum = self.check_rel_hum(rel_hum)?;
+        if rel_hum == 0.0 {
+            // Any dry-bulb temperature, or none, has a relative humidity of 0
+            return Err(PsychroError::DegeneratePair {
+                pair: pair.description(),
+            });
+        }
+
+        let (_, t_max) = self.units.t_dry_bulb_bounds();
+        let sat_vap_pres = vap_pres.into() / rel_hum;
+        let t_dry_bulb =
+            self.t_dew_point_in_moist_air(sat_vap_pres.into_quantity(), pressure, |vap_pres| {
+                self.get_t_dew_point_from_vap_pres(t_max.into_quantity(), vap_pres)
+            })?;
+        Ok(t_dry_bulb.into())
+    }
+}
+
+/******************************************************************************************************
+ * Lazily computed moist air state
+ *****************************************************************************************************/
+
+/// LazyMoistAirState holds the dry-bulb temperature, humidity ratio and pressure of moist air, and computes
+/// each other property the first time it is requested, keeping it for later requests. Callers needing
+/// different subsets of properties then only pay for those, e.g. the wet-bulb solver runs only if the
+/// wet-bulb temperature is requested. See MoistAirState for a state with every property computed at once.
+///
+/// Errors are not kept: a property whose computation failed is computed again on the next request.
+///
+/// # Example
+///     use psychrolib::{Psychrolib, UnitSystem};
+///
+///     let psych = Psychrolib::new(UnitSystem::SI);
+///     let state = psych.lazy_state(25.0, 0.01, 101325.0);
+///
+///     assert!((state.get_rel_hum().unwrap() - 0.51).abs() < 0.01);
+///     assert!((state.get_t_wet_bulb().unwrap() - 18.0).abs() < 0.1);
+#[derive(Debug, Clone)]
+pub struct LazyMoistAirState<'a, U: Units = UnitSystem> {
+    psych: &'a Psychrolib<U>,
+    t_dry_bulb: U::Temperature,
+    hum_ratio: U::HumRatio,
+    pressure: U::Pressure,
+    vap_pres: Cell<Option<U::Pressure>>,
+    sat_vap_pres: Cell<Option<U::Pressure>>,
+    t_wet_bulb: Cell<Option<U::Temperature>>,
+    t_dew_point: Cell<Option<U::Temperature>>,
+    rel_hum: Cell<Option<U::RelHum>>,
+    moist_air_enthalpy: Cell<Option<U::Enthalpy>>,
+    moist_air_volume: Cell<Option<f64>>,
+    moist_air_density: Cell<Option<f64>>,
+    degree_of_saturation: Cell<Option<f64>>,
+    vapor_pressure_deficit: Cell<Option<U::Pressure>>,
+}
+
+impl<U: Units> Psychrolib<U> {
+    /// Returns a LazyMoistAirState given dry-bulb temperature in °F [IP] or °C [SI], humidity ratio
+    /// in lb_H₂O lb_Air⁻¹ [IP] or kg_H₂O kg_Air⁻¹ [SI], and pressure in Psi [IP] or Pa [SI].
+    ///
+    /// Inputs are checked when the properties depending on them are computed.
+    pub fn lazy_state(
+        &self,
+        t_dry_bulb: U::Temperature,
+        hum_ratio: U::HumRatio,
+        pressure: U::Pressure,
+    ) -> LazyMoistAirState<'_, U> {
+        LazyMoistAirState {
+            psych: self,
+            t_dry_bulb,
+            hum_ratio,
+            pressure,
+            vap_pres: Cell::new(None),
+            sat_vap_pres: Cell::new(None),
+            t_wet_bulb: Cell::new(None),
+            t_dew_point: Cell::new(None),
+            rel_hum: Cell::new(None),
+            moist_air_enthalpy: Cell::new(None),
+            moist_air_volume: Cell::new(None),
+            moist_air_density: Cell::new(None),
+            degree_of_saturation: Cell::new(None),
+            vapor_pressure_deficit: Cell::new(None),
+        }
+    }
+}
+
+// Returns the value kept in `cache`, computing and keeping it first if needed.
+fn memoize<T: Copy>(
+    cache: &Cell<Option<T>>,
+    compute: impl FnOnce() -> Result<T, PsychroError>,
+) -> Result<T, PsychroError> {
+    if let Some(value) = cache.get() {
+        return Ok(value);
+    }
+
+    let value = compute()?;
+    cache.set(Some(value));
+    Ok(value)
+}
+
+impl<'a, U: Units> LazyMoistAirState<'a, U> {
+    /// Returns dry-bulb temperature in °F [IP] or °C [SI]
+    pub fn get_t_dry_bulb(&self) -> U::Temperature {
+        self.t_dry_bulb
+    }
+
+    /// Returns humidity ratio in lb_H₂O lb_Air⁻¹ [IP] or kg_H₂O kg_Air⁻¹ [SI]
+    pub fn get_hum_ratio(&self) -> U::HumRatio {
+        self.hum_ratio
+    }
+
+    /// Returns atmospheric pressure in Psi [IP] or Pa [SI]
+    pub fn get_pressure(&self) -> U::Pressure {
+        self.pressure
+    }
+
+    /// Returns partial pressure of water vapor in moist air in Psi [IP] or Pa [SI]
+    pub fn get_vap_pres(&self) -> Result<U::Pressure, PsychroError> {
+        memoize(&self.vap_pres, || {
+            self.psych
+                .get_vap_pres_from_hum_ratio(self.hum_ratio, self.pressure)
+        })
+    }
+
+    /// Returns saturation vapor pressure of water vapor in moist air at the dry-bulb temperature in Psi [IP]
+    /// or Pa [SI], including the enhancement factor if enabled
+    pub fn get_sat_vap_pres(&self) -> Result<U::Pressure, PsychroError> {
+        memoize(&self.sat_vap_pres, || {
+            Ok(self
+                .psych
+                .sat_vap_pres_in_moist_air(self.t_dry_bulb, self.pressure)?
+                .into_quantity())
+        })
+    }
+
+    /// Returns wet-bulb temperature in °F [IP] or °C [SI]
+    pub fn get_t_wet_bulb(&self) -> Result<U::Temperature, PsychroError> {
+        memoize(&self.t_wet_bulb, || {
+            self.psych
+                .get_t_wet_bulb_from_hum_ratio(self.t_dry_bulb, self.hum_ratio, self.pressure)
+        })
+    }
+
+    /// Returns dew-point temperature in °F [IP] or °C [SI]
+    pub fn get_t_dew_point(&self) -> Result<U::Temperature, PsychroError> {
+        memoize(&self.t_dew_point, || {
+            self.psych
+                .t_dew_point_in_moist_air(self.get_vap_pres()?, self.pressure, |vap_pres| {
+                    self.psych
+                        .get_t_dew_point_from_vap_pres(self.t_dry_bulb, vap_pres)
+                })
+        })
+    }
+
+    /// Returns relative humidity in range [0, 1]
+    pub fn get_rel_hum(&self) -> Result<U::RelHum, PsychroError> {
+        memoize(&self.rel_hum, || {
+            let vap_pres: f64 = self.get_vap_pres()?.into();
+            let sat_vap_pres: f64 = self.get_sat_vap_pres()?.into();
+            Ok((vap_pres / sat_vap_pres).into_quantity())
+        })
+    }
+
+    /// Returns moist air enthalpy in Btu lb⁻¹ [IP] or J kg⁻¹ [SI]
+    pub fn get_moist_air_enthalpy(&self) -> Result<U::Enthalpy, PsychroError> {
+        memoize(&self.moist_air_enthalpy, || {
+            self.psych
+                .get_moist_air_enthalpy(self.t_dry_bulb, self.hum_ratio)
+        })
+    }
+
+    /// Returns specific volume of moist air in ft³ lb⁻¹ of dry air [IP] or in m³ kg⁻¹ of dry air [SI]
+    pub fn get_moist_air_volume(&self) -> Result<f64, PsychroError> {
+        memoize(&self.moist_air_volume, || {
+            self.psych
+                .get_moist_air_volume(self.t_dry_bulb, self.hum_ratio, self.pressure)
+        })
+    }
+
+    /// Returns moist air density in lb ft⁻³ [IP] or kg m⁻³ [SI]
+    pub fn get_moist_air_density(&self) -> Result<f64, PsychroError> {
+        memoize(&self.moist_air_density, || {
+            let bounded_hum_ratio = self.psych.bounded_hum_ratio(self.hum_ratio)?;
+            Ok((1.0 + bounded_hum_ratio) / self.get_moist_air_volume()?)
+        })
+    }
+
+    /// Returns degree of saturation [unitless]
+    pub fn get_degree_of_saturation(&self) -> Result<f64, PsychroError> {
+        memoize(&self.degree_of_saturation, || {
+            self.psych
+                .get_degree_of_saturation(self.t_dry_bulb, self.hum_ratio, self.pressure)
+        })
+    }
+
+    /// Returns vapor pressure deficit in Psi [IP] or Pa [SI]
+    pub fn get_vapor_pressure_deficit(&self) -> Result<U::Pressure, PsychroError> {
+        memoize(&self.vapor_pressure_deficit, || {
+            let rel_hum: f64 = self.get_rel_hum()?.into();
+            let sat_vap_pres: f64 = self.get_sat_vap_pres()?.into();
+            Ok((sat_vap_pres * (1.0 - rel_hum)).into_quantity())
+        })
+    }
+
+    /// Returns a MoistAirState with every property, computing those not requested yet
+    pub fn to_moist_air_state(&self) -> Result<MoistAirState<U>, PsychroError> {
+        Ok(MoistAirState {
+            t_dry_bulb: self.t_dry_bulb,
+            t_wet_bulb: self.get_t_wet_bulb()?,
+            t_dew_point: self.get_t_dew_point()?,
+            rel_hum: self.get_rel_hum()?,
+            hum_ratio: self.hum_ratio,
+            vap_pres: self.get_vap_pres()?,
+            moist_air_enthalpy: self.get_moist_air_enthalpy()?,
+            moist_air_volume: self.get_moist_air_volume()?,
+            degree_of_saturation: self.get_degree_of_saturation()?,
+            pressure: self.pressure,
+            units: self.psych.units,
+        })
+    }
+}
+
+/******************************************************************************************************
+ * Warm-started solvers for time series
+ *****************************************************************************************************/
+
+/// SolverContext solves successive samples of a time series with a Psychrolib, starting the iterative solvers
+/// from the dew-point and wet-bulb temperatures of the previous sample rather than from the dry-bulb
+/// temperature. Close samples, e.g. minute-level trends, then need fewer iterations.
+///
+/// Results agree with those of the Psychrolib within its tolerance. A sample for which a solver fails or
+/// returns NaN does not change the seeds.
+///
+/// # Example
+///     use psychrolib::{Psychrolib, UnitSystem};
+///
+///     let psych = Psychrolib::new(UnitSystem::SI);
+///     let mut context = psych.solver_context();
+///
+///     for &(t_dry_bulb, hum_ratio) in [(25.0, 0.0100), (25.1, 0.0101), (25.2, 0.0101)].iter() {
+///         let t_wet_bulb = context.get_t_wet_bulb_from_hum_ratio(t_dry_bulb, hum_ratio, 101325.0).unwrap();
+///         let t_cold = psych.get_t_wet_bulb_from_hum_ratio(t_dry_bulb, hum_ratio, 101325.0).unwrap();
+///
+///         assert!((t_wet_bulb - t_cold).abs() < psych.get_tolerance());
+///     }
+#[derive(Debug, Clone)]
+pub struct SolverContext<'a, U: Units = UnitSystem> {
+    psych: &'a Psychrolib<U>,
+    t_dew_point: Option<f64>,
+    t_wet_bulb: Option<f64>,
+}
+
+impl<U: Units> Psychrolib<U> {
+    /// Returns a SolverContext to solve a time series with warm-started solvers
+    pub fn solver_context(&self) -> SolverContext<'_, U> {
+        SolverContext {
+            psych: self,
+            t_dew_point: None,
+            t_wet_bulb: None,
+        }
+    }
+}
+
+impl<'a, U: Units> SolverContext<'a, U> {
+    /// Forgets the previous solutions, e.g. after a gap in the time series
+    pub fn reset(&mut self) {
+        self.t_dew_point = None;
+        self.t_wet_bulb = None;
+    }
+
+    /// Returns dew-point temperature in °F [IP] or °C [SI] given dry-bulb temperature in °F [IP] or °C [SI]
+    /// and vapor pressure in Psi [IP] or Pa [SI]. See Psychrolib::get_t_dew_point_from_vap_pres.
+    pub fn get_t_dew_point_from_vap_pres(
+        &mut self,
+        t_dry_bulb: U::Temperature,
+        vap_pres: U::Pressure,
+    ) -> Result<U::Temperature, PsychroError> {
+        let (t_dew_point, converged) =
+            self.psych
+                .solve_t_dew_point(t_dry_bulb, vap_pres, self.t_dew_point, None)?;
+        if !converged {
+            return Err(self
+                .psych
+                .convergence_not_reached("get_t_dew_point_from_vap_pres"));
+        }
+
+        self.t_dew_point = seed(t_dew_point, self.t_dew_point);
+        Ok(t_dew_point.into_quantity())
+    }
+
+    /// Returns dew-point temperature in °F [IP] or °C [SI] given dry-bulb temperature in °F [IP] or °C [SI]
+    /// and vapor pressure in Psi [IP] or Pa [SI], together with a report of the Newton-Raphson iterations.
+    /// See Psychrolib::get_t_dew_point_from_vap_pres_with_diagnostics.
+    pub fn get_t_dew_point_from_vap_pres_with_diagnostics(
+        &mut self,
+        t_dry_bulb: U::Temperature,
+        vap_pres: U::Pressure,
+    ) -> Result<(U::Temperature, SolverReport), PsychroError> {
+        let mut report = SolverReport::default();
+        let (t_dew_point, converged) = self.psych.solve_t_dew_point(
+            t_dry_bulb,
+            vap_pres,
+            self.t_dew_point,
+            Some(&mut report),
+        )?;
+
+        if converged {
+            self.t_dew_point = seed(t_dew_point, self.t_dew_point);
+        }
+        Ok((t_dew_point.into_quantity(), report))
+    }
+
+    /// Returns dew-point temperature in °F [IP] or °C [SI] given dry-bulb temperature in °F [IP] or °C [SI],
+    /// humidity ratio in lb_H₂O lb_Air⁻¹ [IP] or kg_H₂O kg_Air⁻¹ [SI], and pressure in Psi [IP] or Pa [SI].
+    /// See Psychrolib::get_t_dew_point_from_hum_ratio.
+    pub fn get_t_dew_point_from_hum_ratio(
+        &mut self,
+        t_dry_bulb: U::Temperature,
+        hum_ratio: U::HumRatio,
+        pressure: U::Pressure,
+    ) -> Result<U::Temperature, PsychroError> {
+        let psych = self.psych;
+        let vap_pres = psych.get_vap_pres_from_hum_ratio(hum_ratio, pressure)?;
+        psych.t_dew_point_in_moist_air(vap_pres, pressure, |vap_pres| {
+            self.get_t_dew_point_from_vap_pres(t_dry_bulb, vap_pres)
+        })
+    }
+
+    /// Returns wet-bulb temperature in °F [IP] or °C [SI] given dry-bulb temperature in °F [IP] or °C [SI],
+    /// humidity ratio in lb_H₂O lb_Air⁻¹ [IP] or kg_H₂O kg_Air⁻¹ [SI], and pressure in Psi [IP] or Pa [SI].
+    /// See Psychrolib::get_t_wet_bulb_from_hum_ratio.
+    pub fn get_t_wet_bulb_from_hum_ratio(
+        &mut self,
+        t_dry_bulb: U::Temperature,
+        hum_ratio: U::HumRatio,
+        pressure: U::Pressure,
+    ) -> Result<U::Temperature, PsychroError> {
+        let t_dew_point = self.get_t_dew_point_from_hum_ratio(t_dry_bulb, hum_ratio, pressure)?;
+        let (t_wet_bulb, converged) = self.psych.solve_t_wet_bulb(
+            t_dry_bulb,
+            hum_ratio,
+            pressure,
+            t_dew_point,
+            self.t_wet_bulb,
+            None,
+        )?;
+        if !converged {
+            return Err(self
+                .psych
+                .convergence_not_reached("get_t_wet_bulb_from_hum_ratio"));
+        }
+
+        self.t_wet_bulb = seed(t_wet_bulb, self.t_wet_bulb);
+        Ok(t_wet_bulb.into_quantity())
+    }
+
+    /// Returns wet-bulb temperature in °F [IP] or °C [SI] given dry-bulb temperature in °F [IP] or °C [SI],
+    /// humidity ratio in lb_H₂O lb_Air⁻¹ [IP] or kg_H₂O kg_Air⁻¹ [SI], and pressure in Psi [IP] or Pa [SI],
+    /// together with a report of the bracketing and bisection iterations.
+    /// See Psychrolib::get_t_wet_bulb_from_hum_ratio_with_diagnostics.
+    pub fn get_t_wet_bulb_from_hum_ratio_with_diagnostics(
+        &mut self,
+        t_dry_bulb: U::Temperature,
+        hum_ratio: U::HumRatio,
+        pressure: U::Pressure,
+    ) -> Result<(U::Temperature, SolverReport), PsychroError> {
+        let t_dew_point = self.get_t_dew_point_from_hum_ratio(t_dry_bulb, hum_ratio, pressure)?;
+        let mut report = SolverReport::default();
+        let (t_wet_bulb, converged) = self.psych.solve_t_wet_bulb(
+            t_dry_bulb,
+            hum_ratio,
+            pressure,
+            t_dew_point,
+            self.t_wet_bulb,
+            Some(&mut report),
+        )?;
+
+        if converged {
+            self.t_wet_bulb = seed(t_wet_bulb, self.t_wet_bulb);
+        }
+        Ok((t_wet_bulb.into_quantity(), report))
+    }
+
+    /// Utility function to calculate humidity ratio, wet-bulb temperature, dew-point temperature,
+    /// vapour pressure, moist air enthalpy, moist air volume, and degree of saturation of air given
+    /// dry-bulb temperature in °F [IP] or °C [SI], relative humidity in range [0, 1],
+    /// and pressure in Psi [IP] or Pa [SI]. See Psychrolib::calc_psychrometrics_from_rel_hum.
+    pub fn calc_psychrometrics_from_rel_hum(
+        &mut self,
+        t_dry_bulb: U::Temperature,
+        rel_hum: U::RelHum,
+        pressure: U::Pressure,
+    ) -> Result<MoistAirState<U>, PsychroError> {
+        let rel_hum = self.psych.check_rel_hum(rel_hum)?.into_quantity();
+        let hum_ratio = self
+            .psych
+            .get_hum_ratio_from_rel_hum(t_dry_bulb, rel_hum, pressure)?;
+        let t_wet_bulb = self.get_t_wet_bulb_from_hum_ratio(t_dry_bulb, hum_ratio, pressure)?;
+        let t_dew_point = self.get_t_dew_point_from_hum_ratio(t_dry_bulb, hum_ratio, pressure)?;
+
+        self.psych.calc_moist_air_state(
+            t_dry_bulb,
+            t_wet_bulb,
+            t_dew_point,
+            rel_hum,
+            hum_ratio,
+            pressure,
+        )
+    }
+
+    /// Utility function to calculate humidity ratio, wet-bulb temperature, relative humidity,
+    /// vapour pressure, moist air enthalpy, moist air volume, and degree of saturation of air given
+    /// dry-bulb temperature in °F [IP] or °C [SI], dew-point temperature in °F [IP] or °C [SI],
+    /// and pressure in Psi [IP] or Pa [SI]. See Psychrolib::calc_psychrometrics_from_t_dew_point.
+    pub fn calc_psychrometrics_from_t_dew_point(
+        &mut self,
+        t_dry_bulb: U::Temperature,
+        t_dew_point: U::Temperature,
+        pressure: U::Pressure,
+    ) -> Result<MoistAirState<U>, PsychroError> {
+        let hum_ratio = self
+            .psych
+            .get_hum_ratio_from_t_dew_point(t_dew_point, pressure)?;
+        let t_wet_bulb = self.get_t_wet_bulb_from_hum_ratio(t_dry_bulb, hum_ratio, pressure)?;
+        let rel_hum = self
+            .psych
+            .get_rel_hum_from_hum_ratio(t_dry_bulb, hum_ratio, pressure)?;
+
+        self.psych.calc_moist_air_state(
+            t_dry_bulb,
+            t_wet_bulb,
+            t_dew_point,
+            rel_hum,
+            hum_ratio,
+            pressure,
+        )
+    }
+
+    /// Utility function to calculate humidity ratio, dew-point temperature, relative humidity,
+    /// vapour pressure, moist air enthalpy, moist air volume, and degree of saturation of air given
+    /// dry-bulb temperature in °F [IP] or °C [SI], wet-bulb temperature in °F [IP] or °C [SI],
+    /// and pressure in Psi [IP] or Pa [SI]. See Psychrolib::calc_psychrometrics_from_t_wet_bulb.
+    pub fn calc_psychrometrics_from_t_wet_bulb(
+        &mut self,
+        t_dry_bulb: U::Temperature,
+        t_wet_bulb: U::Temperature,
+        pressure: U::Pressure,
+    ) -> Result<MoistAirState<U>, PsychroError> {
+        let t_wet_bulb =
+            self.psych
+                .check_below_t_dry_bulb(Property::TWetBulb, t_wet_bulb, t_dry_bulb)?;
+        let hum_ratio = self
+            .psych
+            .get_hum_ratio_from_t_wet_bulb(t_dry_bulb, t_wet_bulb, pressure)?;
+        let t_dew_point = self.get_t_dew_point_from_hum_ratio(t_dry_bulb, hum_ratio, pressure)?;
+        let rel_hum = self
+            .psych
+            .get_rel_hum_from_hum_ratio(t_dry_bulb, hum_ratio, pressure)?;
+
+        self.psych.calc_moist_air_state(
+            t_dry_bulb,
+            t_wet_bulb,
+            t_dew_point,
+            rel_hum,
+            hum_ratio,
+            pressure,
+        )
+    }
+}
+
+// Returns the seed of the next warm start: the new solution, or the previous seed if the solution is NaN.
+fn seed(solution: f64, previous: Option<f64>) -> Option<f64> {
+    if solution.is_finite() {
+        Some(solution)
+    } else {
+        previous
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_units_ip() {
+        let psych = Psychrolib::new(UnitSystem::IP);
+
+        assert_eq!(psych.units, UnitSystem::IP);
+        assert_eq!(psych.get_tolerance(), TOLERANCE_IP);
+        assert_eq!(psych.get_config(), PsychrolibConfig::default());
+    }
+
+    #[test]
+    fn get_unit_test() {
+        let psych = Psychrolib::new(UnitSystem::IP);
+
+        assert_eq!(psych.get_units(), UnitSystem::IP);
+    }
+
+    #[test]
+    fn get_t_rankine_from_t_fahrenheit_test() {
+        assert!((get_t_rankine_from_t_fahrenheit(70.0) - 529.67).abs() < 1e-9);
+        assert!((get_t_fahrenheit_from_t_rankine(529.67) - 70.0).abs() < 1e-9);
+    }
+
+    fn assert_rel(actual: f64, expected: f64, rel: f64) {
+        assert!(
+            (actual - expected).abs() <= rel * expected.abs(),
+            "{} != {}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn get_sat_vap_pres_out_of_range() {
+        let psych = Psychrolib::new(UnitSystem::SI);
+
+        assert!(psych.get_sat_vap_pres(-100.1).is_err());
+        assert!(psych.get_sat_vap_pres(200.1).is_err());
+    }
+
+    #[test]
+    fn get_t_dew_point_from_vap_pres_inverts_get_sat_vap_pres() {
+        let psych = Psychrolib::new(UnitSystem::SI);
+
+        for &t in [-60.0, -20.0, -5.0, 0.0, 0.01, 5.0, 25.0, 50.0, 100.0, 150.0].iter() {
+            let vap_pres = psych.get_sat_vap_pres(t).unwrap();
+            assert!((psych.get_t_dew_point_from_vap_pres(t, vap_pres).unwrap() - t).abs() < 0.001);
+        }
+    }
+
+    #[test]
+    fn get_t_dew_point_from_vap_pres_out_of_range() {
+        let psych = Psychrolib::new(UnitSystem::SI);
+
+        assert!(matches!(
+            psych.get_t_dew_point_from_vap_pres(25.0, 0.0),
+            Err(PsychroError::OutOfRange {
+                property: Property::VapPres,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn t_wet_bulb_rel_hum_t_dew_point_round_trip() {
+        let psych = Psychrolib::new(UnitSystem::SI);
+
+        let t_wet_bulb = psych
+            .get_t_wet_bulb_from_rel_hum(7.0, 0.61, 100000.0)
+            .unwrap();
+        let rel_hum = psych
+            .get_rel_hum_from_t_wet_bulb(7.0, t_wet_bulb, 100000.0)
+            .unwrap();
+        assert!((rel_hum - 0.61).abs() < 0.001);
+
+        let t_dew_point = psych
+            .get_t_dew_point_from_t_wet_bulb(25.0, 20.0, 101325.0)
+            .unwrap();
+        let t_wet_bulb = psych
+            .get_t_wet_bulb_from_t_dew_point(25.0, t_dew_point, 101325.0)
+            .unwrap();
+        assert!((t_wet_bulb - 20.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn t_wet_bulb_above_t_dry_bulb() {
+        let psych = Psychrolib::new(UnitSystem::IP);
+
+        assert!(psych
+            .get_rel_hum_from_t_wet_bulb(70.0, 71.0, 14.696)
+            .is_err());
+        assert!(psych
+            .get_t_dew_point_from_t_wet_bulb(70.0, 71.0, 14.696)
+            .is_err());
+        assert!(psych
+            .get_t_wet_bulb_from_rel_hum(70.0, 1.1, 14.696)
+            .is_err());
+    }
+
+    #[test]
+    fn rel_hum_round_trips_si() {
+        let psych = Psychrolib::new(UnitSystem::SI);
+
+        let hum_ratio = psych
+            .get_hum_ratio_from_rel_hum(25.0, 0.5, 101325.0)
+            .unwrap();
+        assert_rel(
+            psych
+                .get_rel_hum_from_hum_ratio(25.0, hum_ratio, 101325.0)
+                .unwrap(),
+            0.5,
+            1e-9,
+        );
+
+        let t_dew_point = psych.get_t_dew_point_from_rel_hum(25.0, 0.5).unwrap();
+        assert!(
+            (psych
+                .get_rel_hum_from_t_dew_point(25.0, t_dew_point)
+                .unwrap()
+                - 0.5)
+                .abs()
+                < 0.0001
+        );
+    }
+
+    #[test]
+    fn negative_hum_ratio_is_rejected() {
+        let psych = Psychrolib::new(UnitSystem::SI);
+
+        assert!(psych.get_vap_pres_from_hum_ratio(-0.001, 101325.0).is_err());
+        assert!(psych
+            .get_rel_hum_from_hum_ratio(25.0, -0.001, 101325.0)
+            .is_err());
+        assert!(psych
+            .get_t_dew_point_from_hum_ratio(25.0, -0.001, 101325.0)
+            .is_err());
+        assert!(psych.get_specific_hum_from_hum_ratio(-0.001).is_err());
+        assert!(psych.get_hum_ratio_from_specific_hum(1.0).is_err());
+        assert_eq!(
+            psych.get_vap_pres_from_hum_ratio(0.0, 101325.0),
+            psych.get_vap_pres_from_hum_ratio(MIN_HUM_RATIO, 101325.0)
+        );
+    }
+
+    #[test]
+    fn errors_carry_offending_input() {
+        let psych = Psychrolib::new(UnitSystem::SI);
+
+        assert_eq!(
+            psych.get_sat_vap_pres(250.0),
+            Err(PsychroError::OutOfRange {
+                property: Property::TDryBulb,
+                value: 250.0,
+                min: -100.0,
+                max: 200.0,
+                units: UnitSystem::SI,
+            })
+        );
+        assert_eq!(
+            psych.get_rel_hum_from_t_dew_point(20.0, 21.0),
+            Err(PsychroError::AboveDryBulb {
+                property: Property::TDewPoint,
+                value: 21.0,
+                t_dry_bulb: 20.0,
+                units: UnitSystem::SI,
+            })
+        );
+        assert!(matches!(
+            psych.get_hum_ratio_from_rel_hum(20.0, 80.0, 101325.0),
+            Err(PsychroError::OutOfRange {
+                property: Property::RelHum,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn errors_display() {
+        let psych = Psychrolib::new(UnitSystem::IP);
+
+        assert_eq!(
+            psych.get_sat_vap_pres(400.0).unwrap_err().to_string(),
+            "Dry bulb temperature of 400 °F is outside range [-148, 392] °F"
+        );
+        assert_eq!(
+            psych
+                .get_t_dew_point_from_t_wet_bulb(70.0, 72.5, 14.696)
+                .unwrap_err()
+                .to_string(),
+            "Wet bulb temperature of 72.5 °F is above dry bulb temperature of 70 °F"
+        );
+        assert_eq!(
+            psych
+                .get_moist_air_enthalpy(70.0, -0.01)
+                .unwrap_err()
+                .to_string(),
+            "Humidity ratio of -0.01 lb_H₂O lb_Air⁻¹ is outside range [0, inf) lb_H₂O lb_Air⁻¹"
+        );
+        assert_eq!(
+            psych
+                .get_hum_ratio_from_specific_hum(1.0)
+                .unwrap_err()
+                .to_string(),
+            "Specific humidity of 1 is outside range [0, 1)"
+        );
+    }
+
+    #[test]
+    fn set_units_updates_tolerance() {
+        let mut psych = Psychrolib::new(UnitSystem::SI);
+        psych.set_units(UnitSystem::IP);
+
+        assert_eq!(psych.get_tolerance(), TOLERANCE_IP);
+        assert_eq!(
+            psych.get_sat_vap_pres(400.0).unwrap_err(),
+            PsychroError::OutOfRange {
+                property: Property::TDryBulb,
+                value: 400.0,
+                min: -148.0,
+                max: 392.0,
+                units: UnitSystem::IP,
+            }
+        );
+    }
+
+    #[test]
+    fn set_units_converts_configured_tolerance() {
+        let mut psych = Psychrolib::new(UnitSystem::SI).with_tolerance(0.01);
+        psych.set_units(UnitSystem::IP);
+
+        assert_rel(psych.get_tolerance(), 0.018, 1e-12);
+
+        psych.set_units(UnitSystem::SI);
+
+        assert_rel(psych.get_tolerance(), 0.01, 1e-12);
+    }
+
+    #[test]
+    fn config_sets_solver_settings() {
+        let default = Psychrolib::new(UnitSystem::SI);
+        let t_wet_bulb = default
+            .get_t_wet_bulb_from_hum_ratio(30.0, 0.01, 101325.0)
+            .unwrap();
+
+        // Looser and tighter tolerances bracket the default result
+        let fast = default.with_tolerance(0.1);
+        let lab = default.with_tolerance(1e-8);
+        assert_eq!(fast.get_tolerance(), 0.1);
+        assert!(
+            (fast
+                .get_t_wet_bulb_from_hum_ratio(30.0, 0.01, 101325.0)
+                .unwrap()
+                - t_wet_bulb)
+                .abs()
+                < 0.1
+        );
+        assert!(
+            (lab.get_t_wet_bulb_from_hum_ratio(30.0, 0.01, 101325.0)
+                .unwrap()
+                - t_wet_bulb)
+                .abs()
+                < 0.001
+        );
+
+        // Too few iterations to converge
+        assert_eq!(
+            default
+                .with_max_iter_count(1)
+                .get_t_dew_point_from_vap_pres(30.0, 1000.0),
+            Err(PsychroError::ConvergenceNotReached {
+                function: "get_t_dew_point_from_vap_pres",
+                iterations: 1,
+                units: UnitSystem::SI,
+            })
+        );
+        assert_eq!(
+            default
+                .with_max_iter_count(5)
+                .get_t_wet_bulb_from_hum_ratio(30.0, 0.01, 101325.0),
+            Err(PsychroError::ConvergenceNotReached {
+                function: "get_t_wet_bulb_from_hum_ratio",
+                iterations: 5,
+                units: UnitSystem::SI,
+            })
+        );
+
+        // Minimum humidity ratio and clamping
+        assert_eq!(
+            default
+                .with_min_hum_ratio(1e-5)
+                .get_hum_ratio_from_vap_pres(0.0, 101325.0),
+            Ok(1e-5)
+        );
+        assert_eq!(
+            default
+                .with_hum_ratio_clamping(false)
+                .get_hum_ratio_from_vap_pres(0.0, 101325.0),
+            Ok(0.0)
+        );
+
+        let config = PsychrolibConfig {
+            tolerance: Some(0.1),
+            max_iter_count: 3,
+            min_hum_ratio: 1e-5,
+            clamp_hum_ratio: false,
+            out_of_range: OutOfRangePolicy::Nan,
+            wet_bulb_solver: WetBulbSolver::Brent,
+            saturation_model: SaturationModel::Sonntag,
+            saturation_phase: SaturationPhase::Water,
+            enhancement_factor: true,
+            warning_handler: default.get_config().warning_handler,
+        };
+        assert_eq!(default.with_config(config).get_config(), config);
+    }
+
+    #[test]
+    fn out_of_range_policy_clamp() {
+        let psych =
+            Psychrolib::new(UnitSystem::SI).with_out_of_range_policy(OutOfRangePolicy::Clamp);
+
+        assert_eq!(
+            psych.get_hum_ratio_from_rel_hum(25.0, 1.2, 101325.0),
+            psych.get_hum_ratio_from_rel_hum(25.0, 1.0, 101325.0)
+        );
+        assert_eq!(psych.get_sat_vap_pres(250.0), psych.get_sat_vap_pres(200.0));
+        assert_eq!(
+            psych.get_vap_pres_from_hum_ratio(-0.01, 101325.0),
+            psych.get_vap_pres_from_hum_ratio(0.0, 101325.0)
+        );
+        // Dew point above dry bulb is lowered to the dry bulb, i.e. saturation
+        assert_eq!(psych.get_rel_hum_from_t_dew_point(20.0, 22.0), Ok(1.0));
+        assert!(psych
+            .get_rel_hum_from_vap_pres(25.0, f64::NAN)
+            .unwrap()
+            .is_nan());
+
+        // States hold the clamped inputs, consistent with the properties computed from them
+        let expected = psych
+            .calc_psychrometrics_from_rel_hum(25.0, 1.0, 101325.0)
+            .unwrap();
+        let mut context = psych.solver_context();
+        for state in [
+            psych.calc_psychrometrics_from_rel_hum(25.0, 1.2, 101325.0),
+            context.calc_psychrometrics_from_rel_hum(25.0, 1.2, 101325.0),
+        ]
+        .iter()
+        {
+            let state = state.unwrap();
+            assert_eq!(state.rel_hum, 1.0);
+            assert_eq!(state.hum_ratio, expected.hum_ratio);
+        }
+        let expected = psych
+            .calc_psychrometrics_from_t_wet_bulb(20.0, 20.0, 101325.0)
+            .unwrap();
+        for state in [
+            psych.calc_psychrometrics_from_t_wet_bulb(20.0, 22.0, 101325.0),
+            context.calc_psychrometrics_from_t_wet_bulb(20.0, 22.0, 101325.0),
+        ]
+        .iter()
+        {
+            let state = state.unwrap();
+            assert_eq!(state.t_wet_bulb, 20.0);
+            assert_eq!(state.hum_ratio, expected.hum_ratio);
+        }
+    }
+
+    #[test]
+    fn warning_handler() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        static WARNINGS: AtomicUsize = AtomicUsize::new(0);
+        fn count_warning(error: &PsychroError) {
+            assert!(matches!(error, PsychroError::OutOfRange { .. }));
+            WARNINGS.fetch_add(1, Ordering::SeqCst);
+        }
+
+        let psych = Psychrolib::new(UnitSystem::SI)
+            .with_out_of_range_policy(OutOfRangePolicy::Clamp)
+            .with_warning_handler(count_warning);
+
+        // Only clamped inputs are reported
+        psych
+            .get_hum_ratio_from_rel_hum(25.0, 0.5, 101325.0)
+            .unwrap();
+        assert_eq!(WARNINGS.load(Ordering::SeqCst), 0);
+        psych
+            .get_hum_ratio_from_rel_hum(25.0, 1.2, 101325.0)
+            .unwrap();
+        assert_eq!(WARNINGS.load(Ordering::SeqCst), 1);
+        psych.get_sat_vap_pres(f64::NAN).unwrap();
+        assert_eq!(WARNINGS.load(Ordering::SeqCst), 1);
+
+        // Strict policy returns the error without calling the handler
+        assert!(psych
+            .with_out_of_range_policy(OutOfRangePolicy::Strict)
+            .get_hum_ratio_from_rel_hum(25.0, 1.2, 101325.0)
+            .is_err());
+        assert_eq!(WARNINGS.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn out_of_range_policy_nan() {
+        let psych = Psychrolib::new(UnitSystem::SI).with_out_of_range_policy(OutOfRangePolicy::Nan);
+
+        assert!(psych
+            .get_hum_ratio_from_rel_hum(25.0, 1.2, 101325.0)
+            .unwrap()
+            .is_nan());
+        assert!(psych.get_sat_vap_pres(250.0).unwrap().is_nan());
+        assert!(psych
+            .get_t_dew_point_from_hum_ratio(25.0, -0.01, 101325.0)
+            .unwrap()
+            .is_nan());
+        assert!(psych
+            .get_t_wet_bulb_from_t_dew_point(20.0, 22.0, 101325.0)
+            .unwrap()
+            .is_nan());
+        assert!(psych.get_hum_ratio_from_specific_hum(1.0).unwrap().is_nan());
+
+        // NaN inputs propagate through the iterative solvers instead of failing to converge
+        assert!(psych
+            .get_t_wet_bulb_from_hum_ratio(f64::NAN, 0.01, 101325.0)
+            .unwrap()
+            .is_nan());
+        assert!(psych
+            .get_t_dew_point_from_vap_pres(25.0, f64::NAN)
+            .unwrap()
+            .is_nan());
+        let state = psych
+            .calc_psychrometrics_from_rel_hum(25.0, f64::NAN, 101325.0)
+            .unwrap();
+        assert_eq!(state.t_dry_bulb, 25.0);
+        assert!(state.t_wet_bulb.is_nan());
+        assert!(state.t_dew_point.is_nan());
+        assert!(state.hum_ratio.is_nan());
+        assert!(state.moist_air_enthalpy.is_nan());
+
+        // States hold NaN in place of the out-of-range inputs, like every property computed from them
+        let mut context = psych.solver_context();
+        for state in [
+            psych.calc_psychrometrics_from_rel_hum(25.0, 1.2, 101325.0),
+            context.calc_psychrometrics_from_rel_hum(25.0, 1.2, 101325.0),
+        ]
+        .iter()
+        {
+            let state = state.unwrap();
+            assert!(state.rel_hum.is_nan());
+            assert!(state.hum_ratio.is_nan());
+        }
+        for state in [
+            psych.calc_psychrometrics_from_t_wet_bulb(20.0, 22.0, 101325.0),
+            context.calc_psychrometrics_from_t_wet_bulb(20.0, 22.0, 101325.0),
+        ]
+        .iter()
+        {
+            let state = state.unwrap();
+            assert!(state.t_wet_bulb.is_nan());
+            assert!(state.rel_hum.is_nan());
+        }
+
+        // Valid inputs are unaffected
+        assert_eq!(
+            psych.get_hum_ratio_from_rel_hum(25.0, 0.5, 101325.0),
+            Psychrolib::new(UnitSystem::SI).get_hum_ratio_from_rel_hum(25.0, 0.5, 101325.0)
+        );
+    }
+
+    #[test]
+    fn out_of_range_policy_strict_rejects_nan() {
+        let psych = Psychrolib::new(UnitSystem::SI);
+
+        assert!(psych
+            .get_vap_pres_from_hum_ratio(f64::NAN, 101325.0)
+            .is_err());
+        assert!(psych.get_sat_vap_pres(f64::NAN).is_err());
+    }
+
+    #[test]
+    fn solver_diagnostics() {
+        let psych = Psychrolib::new(UnitSystem::SI);
+
+        let t_dew_point = psych.get_t_dew_point_from_vap_pres(30.0, 1500.0).unwrap();
+        let (t, report) = psych
+            .get_t_dew_point_from_vap_pres_with_diagnostics(30.0, 1500.0)
+            .unwrap();
+        assert_eq!(t, t_dew_point);
+        assert!(report.converged && !report.hit_bound);
+        assert!(report.residual <= psych.get_tolerance());
+        assert_eq!(report.history.len(), report.iterations);
+        let last = report.history.last().unwrap();
+        assert!(last.lower <= t_dew_point && t_dew_point <= last.upper);
+
+        let t_wet_bulb = psych
+            .get_t_wet_bulb_from_hum_ratio(30.0, 0.01, 101325.0)
+            .unwrap();
+        let (t, report) = psych
+            .get_t_wet_bulb_from_hum_ratio_with_diagnostics(30.0, 0.01, 101325.0)
+            .unwrap();
+        assert_eq!(t, t_wet_bulb);
+        assert!(report.converged && !report.hit_bound);
+        assert_eq!(report.history.len(), report.iterations);
+        for step in report.history.iter() {
+            assert!(step.lower <= t_wet_bulb && t_wet_bulb <= step.upper);
+        }
+
+        // Stopping at the iteration cap is reported instead of failing
+        let capped = psych.with_max_iter_count(4);
+        let (_, report) = capped
+            .get_t_wet_bulb_from_hum_ratio_with_diagnostics(30.0, 0.01, 101325.0)
+            .unwrap();
+        assert!(!report.converged);
+        assert_eq!(report.iterations, 4);
+        assert!(capped
+            .get_t_wet_bulb_from_hum_ratio(30.0, 0.01, 101325.0)
+            .is_err());
+
+        // Saturated air: the wet bulb is the dry bulb, at the end of the bracket
+        let (_, report) = psych
+            .get_t_wet_bulb_from_hum_ratio_with_diagnostics(
+                20.0,
+                psych.get_sat_hum_ratio(20.0, 101325.0).unwrap(),
+                101325.0,
+            )
+            .unwrap();
+        assert!(report.hit_bound);
+    }
+
+    #[test]
+    fn solver_reports_respect_iteration_cap() {
+        for max_iter_count in 1..20 {
+            let psych = Psychrolib::new(UnitSystem::SI).with_max_iter_count(max_iter_count);
+            let expected = |function: &'static str, (t, report): (f64, SolverReport)| {
+                assert!(report.iterations <= max_iter_count);
+                if report.converged {
+                    return Ok(t);
+                }
+                assert_eq!(report.iterations, max_iter_count);
+                Err(PsychroError::ConvergenceNotReached {
+                    function,
+                    iterations: report.iterations,
+                    units: UnitSystem::SI,
+                })
+            };
+
+            for &hum_ratio in [0.01, 0.0272].iter() {
+                let vap_pres = psych
+                    .get_vap_pres_from_hum_ratio(hum_ratio, 101325.0)
+                    .unwrap();
+                let t_dew_point = psych.get_t_dew_point_from_vap_pres(30.0, vap_pres);
+                assert_eq!(
+                    t_dew_point,
+                    expected(
+                        "get_t_dew_point_from_vap_pres",
+                        psych
+                            .get_t_dew_point_from_vap_pres_with_diagnostics(30.0, vap_pres)
+                            .unwrap()
+                    )
+                );
+
+                // The wet bulb is searched from the dew point, which must converge first
+                for &solver in [WetBulbSolver::Bisection, WetBulbSolver::Brent].iter() {
+                    let psych = psych.with_wet_bulb_solver(solver);
+                    let t_wet_bulb = psych.get_t_wet_bulb_from_hum_ratio(30.0, hum_ratio, 101325.0);
+                    let diagnostics = psych
+                        .get_t_wet_bulb_from_hum_ratio_with_diagnostics(30.0, hum_ratio, 101325.0);
+                    match t_dew_point {
+                        Ok(_) => assert_eq!(
+                            t_wet_bulb,
+                            expected("get_t_wet_bulb_from_hum_ratio", diagnostics.unwrap())
+                        ),
+                        Err(error) => {
+                            assert_eq!(t_wet_bulb, Err(error));
+                            assert_eq!(diagnostics, Err(error));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn warm_started_solvers_match_and_save_iterations() {
+        let psych = Psychrolib::new(UnitSystem::SI);
+        let mut context = psych.solver_context();
+        let (mut warm_iterations, mut cold_iterations) = (0, 0);
+
+        // A slowly drifting hour of minute-level samples
+        for minute in 0..60 {
+            let t_dry_bulb = 22.0 + 0.02 * minute as f64;
+            let hum_ratio = 0.008 + 1e-5 * minute as f64;
+
+            let (t_warm, warm) = context
+                .get_t_wet_bulb_from_hum_ratio_with_diagnostics(t_dry_bulb, hum_ratio, 101325.0)
+                .unwrap();
+            let (t_cold, cold) = psych
+                .get_t_wet_bulb_from_hum_ratio_with_diagnostics(t_dry_bulb, hum_ratio, 101325.0)
+                .unwrap();
+            assert!(warm.converged);
+            assert!((t_warm - t_cold).abs() <= psych.get_tolerance());
+            warm_iterations += warm.iterations;
+            cold_iterations += cold.iterations;
+
+            let vap_pres = psych
+                .get_vap_pres_from_hum_ratio(hum_ratio, 101325.0)
+                .unwrap();
+            let (t_warm, warm) = context
+                .get_t_dew_point_from_vap_pres_with_diagnostics(t_dry_bulb, vap_pres)
+                .unwrap();
+            let (t_cold, cold) = psych
+                .get_t_dew_point_from_vap_pres_with_diagnostics(t_dry_bulb, vap_pres)
+                .unwrap();
+            assert!((t_warm - t_cold).abs() <= psych.get_tolerance());
+            warm_iterations += warm.iterations;
+            cold_iterations += cold.iterations;
+        }
+        assert!(
+            5 * warm_iterations < 3 * cold_iterations,
+            "{} warm-started iterations for {} cold-started ones",
+            warm_iterations,
+            cold_iterations
+        );
+
+        // A jump in the series is still solved
+        let state = context
+            .calc_psychrometrics_from_rel_hum(35.0, 0.2, 101325.0)
+            .unwrap();
+        let cold = psych
+            .calc_psychrometrics_from_rel_hum(35.0, 0.2, 101325.0)
+            .unwrap();
+        assert!((state.t_wet_bulb - cold.t_wet_bulb).abs() <= psych.get_tolerance());
+        assert!((state.t_dew_point - cold.t_dew_point).abs() <= psych.get_tolerance());
+        assert_eq!(state.hum_ratio, cold.hum_ratio);
+    }
+
+    #[test]
+    fn brent_wet_bulb_matches_bisection() {
+        for &unit_system in [UnitSystem::SI, UnitSystem::IP].iter() {
+            for &tolerance in [None, Some(1e-8)].iter() {
+                let bisection = match tolerance {
+                    Some(tolerance) => Psychrolib::new(unit_system).with_tolerance(tolerance),
+                    None => Psychrolib::new(unit_system),
+                };
+                let brent = bisection.with_wet_bulb_solver(WetBulbSolver::Brent);
+                let tol = bisection.get_tolerance();
+                let (mut brent_iterations, mut bisection_iterations) = (0, 0);
+
+                for i in 0..=12 {
+                    for j in 0..=10 {
+                        let t_dry_bulb = match unit_system {
+                            UnitSystem::SI => -40.0 + 10.0 * i as f64,
+                            UnitSystem::IP => -40.0 + 18.0 * i as f64,
+                        };
+                        let rel_hum = 0.1 * j as f64;
+                        let pressure = bisection.get_standard_atm_pressure(0.0);
+                        let hum_ratio = bisection
+                            .get_hum_ratio_from_rel_hum(t_dry_bulb, rel_hum, pressure)
+                            .unwrap();
+
+                        let (t_bisection, report_bisection) = bisection
+                            .get_t_wet_bulb_from_hum_ratio_with_diagnostics(
+                                t_dry_bulb, hum_ratio, pressure,
+                            )
+                            .unwrap();
+                        let (t_brent, report_brent) = brent
+                            .get_t_wet_bulb_from_hum_ratio_with_diagnostics(
+                                t_dry_bulb, hum_ratio, pressure,
+                            )
+                            .unwrap();
+
+                        assert!(report_brent.converged);
+                        assert!(
+                            (t_brent - t_bisection).abs() <= 1.5 * tol,
+                            "{} != {} at {}, {}",
+                            t_brent,
+                            t_bisection,
+                            t_dry_bulb,
+                            rel_hum
+                        );
+                        for step in report_brent.history.iter() {
+                            assert!(step.lower <= t_brent && t_brent <= step.upper);
+                        }
+                        brent_iterations += report_brent.iterations;
+                        bisection_iterations += report_bisection.iterations;
+                    }
+                }
+
+                assert!(
+                    2 * brent_iterations < bisection_iterations,
+                    "{} iterations with Brent's method for {} with bisection",
+                    brent_iterations,
+                    bisection_iterations
+                );
+            }
+        }
+
+        // Warm-started solvers use the same root finder
+        let psych = Psychrolib::new(UnitSystem::SI).with_wet_bulb_solver(WetBulbSolver::Brent);
+        let mut context = psych.solver_context();
+        for &t_dry_bulb in [25.0, 25.1, 25.3].iter() {
+            let t_wet_bulb = context
+                .get_t_wet_bulb_from_hum_ratio(t_dry_bulb, 0.01, 101325.0)
+                .unwrap();
+            let t_cold = psych
+                .get_t_wet_bulb_from_hum_ratio(t_dry_bulb, 0.01, 101325.0)
+                .unwrap();
+            assert!((t_wet_bulb - t_cold).abs() <= 2.0 * psych.get_tolerance());
+        }
+    }
+
+    #[test]
+    fn solve_module_root_finders() {
+        let psych = Psychrolib::new(UnitSystem::SI);
+        let tol = psych.get_tolerance();
+
+        // Dry-bulb temperature with a target enthalpy at a fixed humidity ratio, which has a closed form
+        let expected = psych
+            .get_t_dry_bulb_from_enthalpy_and_hum_ratio(50000.0, 0.01)
+            .unwrap();
+        let f = |t: f64| Ok(psych.get_moist_air_enthalpy(t, 0.01)? - 50000.0);
+        let d_f = |t: f64| Ok((f(t)?, 1006.0 + 1860.0 * 0.01));
+
+        assert!((solve::bisection(&psych, f, 0.0, 40.0).unwrap() - expected).abs() <= tol);
+        assert!(
+            (solve::bisection(&psych, |t| Ok(-f(t)?), 0.0, 40.0).unwrap() - expected).abs() <= tol
+        );
+        assert!((solve::brent(&psych, f, 0.0, 40.0).unwrap() - expected).abs() <= tol);
+        assert!((solve::brent(&psych, f, 40.0, 0.0).unwrap() - expected).abs() <= tol);
+        assert!((solve::newton(&psych, d_f, 0.0).unwrap() - expected).abs() <= tol);
+        assert!((solve::secant(&psych, f, 0.0, 1.0).unwrap() - expected).abs() <= tol);
+
+        // The tolerance and max number of iterations follow the Psychrolib
+        let lab = psych.with_tolerance(1e-10);
+        assert!((solve::brent(&lab, f, 0.0, 40.0).unwrap() - expected).abs() <= 1e-9);
+        assert_eq!(
+            solve::bisection(&psych.with_max_iter_count(5), f, 0.0, 40.0),
+            Err(PsychroError::ConvergenceNotReached {
+                function: "solve::bisection",
+                iterations: 5,
+                units: UnitSystem::SI,
+            })
+        );
+
+        // Errors of the function and of the bracket
+        assert_eq!(
+            solve::brent(&psych, f, 30.0, 40.0),
+            Err(PsychroError::RootNotBracketed {
+                function: "solve::brent",
+                lower: 30.0,
+                upper: 40.0,
+            })
+        );
+        assert!(solve::secant(
+            &psych,
+            |t| psych.get_sat_vap_pres(t).map(|p| p - 1000.0),
+            300.0,
+            310.0
+        )
+        .is_err());
+        assert_eq!(
+            solve::newton(&psych, |_| Ok((1.0, 0.0)), 0.0),
+            Err(PsychroError::ZeroDerivative {
+                function: "solve::newton",
+                x: 0.0,
+                iterations: 0,
+            })
+        );
+        // Flat beyond 1, where the secant steps to 2 then 5
+        let flat = |t: f64| Ok(if t < 1.0 { t - 2.0 } else { -1.0 });
+        assert_eq!(
+            solve::secant(&psych, flat, 0.0, 0.5),
+            Err(PsychroError::ZeroDerivative {
+                function: "solve::secant",
+                x: 5.0,
+                iterations: 2,
+            })
+        );
+    }
+
+    #[test]
+    fn state_from_any_pair() {
+        for &(units, t_dry_bulb, t_wet_bulb, pressure) in [
+            (UnitSystem::SI, 30.0, 20.0, 101325.0),
+            (UnitSystem::SI, 5.0, -2.0, 90000.0),
+            (UnitSystem::IP, 86.0, 68.0, 14.696),
+        ]
+        .iter()
+        {
+            let psych = Psychrolib::new(units);
+            let tol = psych.get_tolerance();
+            let expected = psych
+                .calc_psychrometrics_from_t_wet_bulb(t_dry_bulb, t_wet_bulb, pressure)
+                .unwrap();
+            let (h, w, rh, v) = (
+                expected.moist_air_enthalpy,
+                expected.hum_ratio,
+                expected.rel_hum,
+                expected.moist_air_volume,
+            );
+            let (t_wb, t_dp) = (expected.t_wet_bulb, expected.t_dew_point);
+
+            for &pair in [
+                PropertyPair::TDryBulbTWetBulb(t_dry_bulb, t_wb),
+                PropertyPair::TDryBulbTDewPoint(t_dry_bulb, t_dp),
+                PropertyPair::TDryBulbRelHum(t_dry_bulb, rh),
+                PropertyPair::TDryBulbHumRatio(t_dry_bulb, w),
+                PropertyPair::TDryBulbEnthalpy(t_dry_bulb, h),
+                PropertyPair::EnthalpyHumRatio(h, w),
+                PropertyPair::EnthalpyRelHum(h, rh),
+                PropertyPair::EnthalpyTWetBulb(h, t_wb),
+                PropertyPair::TDewPointTWetBulb(t_dp, t_wb),
+                PropertyPair::TDewPointRelHum(t_dp, rh),
+                PropertyPair::TWetBulbRelHum(t_wb, rh),
+                PropertyPair::TWetBulbHumRatio(t_wb, w),
+                PropertyPair::RelHumHumRatio(rh, w),
+                PropertyPair::MoistAirVolumeRelHum(v, rh),
+                PropertyPair::MoistAirVolumeHumRatio(v, w),
+            ]
+            .iter()
+            {
+                let state = psych.state_from(pair, pressure).unwrap();
+                assert!(
+                    (state.t_dry_bulb - t_dry_bulb).abs() <= 2.0 * tol,
+                    "{:?}",
+                    pair
+                );
+                assert_rel(state.hum_ratio, w, 1e-3);
+                assert!((state.t_wet_bulb - t_wb).abs() <= 2.0 * tol, "{:?}", pair);
+                assert!((state.t_dew_point - t_dp).abs() <= 2.0 * tol, "{:?}", pair);
+                assert_eq!(state.pressure, pressure);
+            }
+        }
+
+        // Pairs that do not fix a state
+        let psych = Psychrolib::new(UnitSystem::SI);
+        let degenerate = |pair: &'static str| Err(PsychroError::DegeneratePair { pair });
+        let h_sat = psych.get_sat_air_enthalpy(20.0, 101325.0).unwrap();
+        let h_freezing = psych
+            .calc_psychrometrics_from_t_wet_bulb(2.0, 0.0, 101325.0)
+            .unwrap()
+            .moist_air_enthalpy;
+        assert_eq!(
+            psych.state_from(
+                PropertyPair::EnthalpyTWetBulb(h_sat + 100.0, 20.0),
+                101325.0
+            ),
+            degenerate("Enthalpy and wet bulb temperature")
+        );
+        assert_eq!(
+            psych.state_from(PropertyPair::EnthalpyTWetBulb(h_freezing, 0.0), 101325.0),
+            degenerate("Enthalpy and wet bulb temperature")
+        );
+        assert_eq!(
+            psych.state_from(PropertyPair::RelHumHumRatio(0.0, 0.01), 101325.0),
+            degenerate("Relative humidity and humidity ratio")
+        );
+        assert_eq!(
+            psych
+                .state_from(PropertyPair::TWetBulbHumRatio(20.0, 0.02), 101325.0)
+                .unwrap_err()
+                .to_string(),
+            "Wet bulb temperature and humidity ratio do not fix a unique moist air state"
+        );
+    }
+
+    #[test]
+    fn lazy_moist_air_state_matches_and_memoizes() {
+        for &(units, t_dry_bulb, t_wet_bulb, pressure) in [
+            (UnitSystem::SI, 30.0, 20.0, 101325.0),
+            (UnitSystem::IP, 86.0, 68.0, 14.696),
+        ]
+        .iter()
+        {
+            let psych = Psychrolib::new(units);
+            let expected = psych
+                .calc_psychrometrics_from_t_wet_bulb(t_dry_bulb, t_wet_bulb, pressure)
+                .unwrap();
+            let state = psych.lazy_state(t_dry_bulb, expected.hum_ratio, pressure);
+
+            // Only the requested properties and those they depend on are computed
+            assert_rel(state.get_rel_hum().unwrap(), expected.rel_hum, 1e-12);
+            assert!(state.vap_pres.get().is_some());
+            assert!(state.t_wet_bulb.get().is_none());
+            assert!(state.moist_air_volume.get().is_none());
+
+            assert_rel(
+                state.get_moist_air_density().unwrap(),
+                psych
+                    .get_moist_air_density(t_dry_bulb, expected.hum_ratio, pressure)
+                    .unwrap(),
+                1e-12,
+            );
+            assert_rel(
+                state.get_vapor_pressure_deficit().unwrap(),
+                psych
+                    .get_vapor_pressure_deficit(t_dry_bulb, expected.hum_ratio, pressure)
+                    .unwrap(),
+                1e-12,
+            );
+            assert_eq!(
+                state.to_moist_air_state().unwrap(),
+                psych
+                    .state_from(
+                        PropertyPair::TDryBulbHumRatio(t_dry_bulb, expected.hum_ratio),
+                        pressure
+                    )
+                    .unwrap()
+            );
+
+            // Kept values are returned on later requests
+            state.t_wet_bulb.set(Some(0.0));
+            assert_eq!(state.get_t_wet_bulb().unwrap(), 0.0);
+        }
+
+        // Errors are not kept
+        let psych = Psychrolib::new(UnitSystem::SI);
+        let state = psych.lazy_state(30.0, -0.01, 101325.0);
+        assert!(state.get_moist_air_enthalpy().is_err());
+        assert!(state.moist_air_enthalpy.get().is_none());
+    }
+
+    #[test]
+    fn saturation_models() {
+        let si = Psychrolib::new(UnitSystem::SI);
+        let with = |model| si.with_saturation_model(model);
+
+        // Values set by the definitions of the formulae
+        let wagner_pruss = with(SaturationModel::WagnerPruss);
+        assert_rel(wagner_pruss.get_sat_vap_pres(0.01).unwrap(), 611.657, 1e-9);
+        assert_rel(
+            wagner_pruss.get_sat_vap_pres(100.0).unwrap(),
+            101418.0,
+            1e-6,
+        );
+        assert_rel(
+            with(SaturationModel::GoffGratch)
+                .get_sat_vap_pres(0.01)
+                .unwrap(),
+            100.0 * 10.0_f64.powf(0.78614),
+            1e-9,
+        );
+        assert_rel(
+            with(SaturationModel::MagnusTetens)
+                .get_sat_vap_pres(0.0)
+                .unwrap(),
+            611.2,
+            1e-12,
+        );
+        assert_rel(
+            with(SaturationModel::Buck1981)
+                .get_sat_vap_pres(0.0)
+                .unwrap(),
+            611.15,
+            1e-12,
+        );
+        assert_rel(
+            with(SaturationModel::Buck1996)
+                .get_sat_vap_pres(0.0)
+                .unwrap(),
+            611.15,
+            1e-12,
+        );
+
+        for &model in [
+            SaturationModel::Ashrae,
+            SaturationModel::WagnerPruss,
+            SaturationModel::MagnusTetens,
+            SaturationModel::Buck1981,
+            SaturationModel::Buck1996,
+            SaturationModel::GoffGratch,
+            SaturationModel::Sonntag,
+        ]
+        .iter()
+        {
+            let si = with(model);
+            let ip = Psychrolib::new(UnitSystem::IP).with_saturation_model(model);
+
+            for &t in [-40.0, -1.0, 10.0, 60.0].iter() {
+                let t_ip = t * 9.0 / 5.0 + 32.0;
+                let sat_vap_pres = si.get_sat_vap_pres(t).unwrap();
+
+                // All formulations agree closely with each other, and between unit systems
+                assert_rel(
+                    sat_vap_pres,
+                    si.with_saturation_model(SaturationModel::Ashrae)
+                        .get_sat_vap_pres(t)
+                        .unwrap(),
+                    5e-3,
+                );
+                assert_rel(
+                    ip.get_sat_vap_pres(t_ip).unwrap() * PSI_AS_PASCAL,
+                    sat_vap_pres,
+                    1e-6,
+                );
+
+                // Each has its own derivative for the dew-point solver
+                for (psych, t) in [(si, t), (ip, t_ip)].iter() {
+                    let h = 1e-4;
+                    let numeric = (psych.get_sat_vap_pres(t + h).unwrap().ln()
+                        - psych.get_sat_vap_pres(t - h).unwrap().ln())
+                        / (2.0 * h);
+                    assert!(
+                        (psych.d_ln_pws(*t) - numeric).abs() < 1e-6,
+                        "{:?} {}",
+                        model,
+                        t
+                    );
+                }
+                let t_dew_point = si.get_t_dew_point_from_vap_pres(t, sat_vap_pres).unwrap();
+                assert!((t_dew_point - t).abs() < si.get_tolerance());
+            }
+        }
+    }
+
+    #[test]
+    fn enhancement_factor() {
+        let psych = Psychrolib::new(UnitSystem::SI);
+        let real_gas = psych.with_enhancement_factor(true);
+
+        let enhancement_factor = psych.get_enhancement_factor(20.0, 101325.0).unwrap();
+        assert!((enhancement_factor - 1.0040).abs() < 0.0001);
+        assert!(psych.get_enhancement_factor(20.0, 1e6).unwrap() > 1.03);
+        assert!(psych.get_enhancement_factor(-20.0, 101325.0).unwrap() > 1.0);
+        assert_rel(
+            Psychrolib::new(UnitSystem::IP)
+                .get_enhancement_factor(68.0, 14.6959)
+                .unwrap(),
+            enhancement_factor,
+            1e-4,
+        );
+
+        // Off by default
+        assert_eq!(
+            psych.get_sat_hum_ratio(20.0, 800000.0),
+            real_gas
+                .with_enhancement_factor(false)
+                .get_sat_hum_ratio(20.0, 800000.0)
+        );
+
+        for &(t_dry_bulb, pressure) in
+            [(20.0, 101325.0), (20.0, 800000.0), (-10.0, 800000.0)].iter()
+        {
+            let tol = real_gas.get_tolerance();
+            let sat_hum_ratio = real_gas.get_sat_hum_ratio(t_dry_bulb, pressure).unwrap();
+            let ideal_sat_hum_ratio = psych.get_sat_hum_ratio(t_dry_bulb, pressure).unwrap();
+            let factor = real_gas
+                .get_enhancement_factor(t_dry_bulb, pressure)
+                .unwrap();
+            assert!(sat_hum_ratio / ideal_sat_hum_ratio > factor);
+
+            // Saturated air stays saturated with the enhancement factor
+            assert_rel(
+                real_gas
+                    .get_rel_hum_from_hum_ratio(t_dry_bulb, sat_hum_ratio, pressure)
+                    .unwrap(),
+                1.0,
+                1e-12,
+            );
+            let t_dew_point = real_gas
+                .get_t_dew_point_from_hum_ratio(t_dry_bulb, sat_hum_ratio, pressure)
+                .unwrap();
+            assert!((t_dew_point - t_dry_bulb).abs() <= tol);
+            let t_wet_bulb = real_gas
+                .get_t_wet_bulb_from_hum_ratio(t_dry_bulb, sat_hum_ratio, pressure)
+                .unwrap();
+            assert!((t_wet_bulb - t_dry_bulb).abs() <= tol);
+
+            // Round trips at lower humidities
+            let hum_ratio = real_gas
+                .get_hum_ratio_from_rel_hum(t_dry_bulb, 0.4, pressure)
+                .unwrap();
+            let t_dew_point = real_gas
+                .get_t_dew_point_from_hum_ratio(t_dry_bulb, hum_ratio, pressure)
+                .unwrap();
+            assert_rel(
+                real_gas
+                    .get_hum_ratio_from_t_dew_point(t_dew_point, pressure)
+                    .unwrap(),
+                hum_ratio,
+                1e-4,
+            );
+            let mut context = real_gas.solver_context();
+            let t_dew_point_warm = context
+                .get_t_dew_point_from_hum_ratio(t_dry_bulb, hum_ratio, pressure)
+                .unwrap();
+            assert!((t_dew_point_warm - t_dew_point).abs() <= tol);
+            let state = real_gas
+                .state_from(PropertyPair::RelHumHumRatio(0.4, hum_ratio), pressure)
+                .unwrap();
+            assert!((state.t_dry_bulb - t_dry_bulb).abs() <= tol);
+            assert_rel(
+                real_gas
+                    .lazy_state(t_dry_bulb, hum_ratio, pressure)
+                    .get_rel_hum()
+                    .unwrap(),
+                0.4,
+                1e-12,
+            );
+        }
+    }
+
+    #[test]
+    fn saturation_phase() {
+        for &units in [UnitSystem::SI, UnitSystem::IP].iter() {
+            let psych = Psychrolib::new(units);
+            let over_water = psych.with_saturation_phase(SaturationPhase::Water);
+            let over_ice = psych.with_saturation_phase(SaturationPhase::Ice);
+            let tol = psych.get_tolerance();
+            let pressure = psych.get_standard_atm_pressure(0.0);
+            let (below, above) = match units {
+                UnitSystem::SI => (-20.0, 20.0),
+                UnitSystem::IP => (-4.0, 68.0),
+            };
+
+            // Automatic follows ice below the triple point and water above it
+            assert_eq!(
+                psych.get_sat_vap_pres(below),
+                over_ice.get_sat_vap_pres(below)
+            );
+            assert_eq!(
+                psych.get_sat_vap_pres(above),
+                over_water.get_sat_vap_pres(above)
+            );
+            assert_eq!(psych.d_ln_pws(below), over_ice.d_ln_pws(below));
+            assert_eq!(psych.d_ln_pws(above), over_water.d_ln_pws(above));
+
+            // Supercooled water saturates above ice, about 22 % at -20 °C, and the other way around above
+            let ratio = over_water.get_sat_vap_pres(below).unwrap()
+                / psych.get_sat_vap_pres(below).unwrap();
+            assert!((ratio - 1.22).abs() < 0.01);
+            assert!(
+                over_ice.get_sat_vap_pres(above).unwrap() > psych.get_sat_vap_pres(above).unwrap()
+            );
+
+            // The dew-point solver inverts each phase, giving dew points over water and frost points over ice
+            for &(psych, t) in [
+                (over_water, below),
+                (over_ice, above),
+                (over_water, above),
+                (over_ice, below),
+            ]
+            .iter()
+            {
+                let vap_pres = psych.get_sat_vap_pres(t).unwrap();
+                let t_dew_point = psych
+                    .get_t_dew_point_from_vap_pres(above, vap_pres)
+                    .unwrap();
+                assert!((t_dew_point - t).abs() <= tol);
+                let h = 1e-4;
+                let numeric = (psych.get_sat_vap_pres(t + h).unwrap().ln()
+                    - psych.get_sat_vap_pres(t - h).unwrap().ln())
+                    / (2.0 * h);
+                assert!((psych.d_ln_pws(t) - numeric).abs() < 1e-6);
+            }
+            let hum_ratio = psych
+                .get_hum_ratio_from_rel_hum(below, 0.5, pressure)
+                .unwrap();
+            let t_frost_point = psych
+                .get_t_dew_point_from_hum_ratio(below, hum_ratio, pressure)
+                .unwrap();
+            let t_dew_point = over_water
+                .get_t_dew_point_from_hum_ratio(below, hum_ratio, pressure)
+                .unwrap();
+            assert!(t_dew_point < t_frost_point);
+
+            // Relative humidities and the wet bulb follow the phase
+            let rel_hum = over_water
+                .get_rel_hum_from_hum_ratio(below, hum_ratio, pressure)
+                .unwrap();
+            assert_rel(rel_hum, 0.5 / ratio, 1e-3);
+            let t_wet_bulb = over_water
+                .get_t_wet_bulb_from_hum_ratio(below, hum_ratio, pressure)
+                .unwrap();
+            let hum_ratio_back = over_water
+                .get_hum_ratio_from_t_wet_bulb(below, t_wet_bulb, pressure)
+                .unwrap();
+            assert_rel(hum_ratio_back, hum_ratio, 1e-3);
+            assert!(
+                t_wet_bulb
+                    < psych
+                        .get_t_wet_bulb_from_hum_ratio(below, hum_ratio, pressure)
+                        .unwrap()
+            );
+
+            // Every saturation model and the enhancement factor follow the phase
+            let sonntag = psych.with_saturation_model(SaturationModel::Sonntag);
+            assert_eq!(
+                sonntag.get_sat_vap_pres(below),
+                sonntag
+                    .with_saturation_phase(SaturationPhase::Ice)
+                    .get_sat_vap_pres(below)
+            );
+            assert_rel(
+                sonntag
+                    .with_saturation_phase(SaturationPhase::Water)
+                    .get_sat_vap_pres(below)
+                    .unwrap(),
+                over_water.get_sat_vap_pres(below).unwrap(),
+                2e-3,
+            );
+            assert!(
+                over_water.get_enhancement_factor(below, pressure)
+                    != psych.get_enhancement_factor(below, pressure)
+            );
+
+            // The same state has distinct frost and dew points, each giving back its humidity ratio
+            let hum_ratio = psych
+                .get_hum_ratio_from_rel_hum(above, 0.5, pressure)
+                .unwrap();
+            let t_frost_point = over_ice
+                .get_t_dew_point_from_hum_ratio(above, hum_ratio, pressure)
+                .unwrap();
+            let t_dew_point = over_water
+                .get_t_dew_point_from_hum_ratio(above, hum_ratio, pressure)
+                .unwrap();
+            assert!(t_frost_point < t_dew_point - 10.0 * tol);
+            for &(psych, t) in [(over_ice, t_frost_point), (over_water, t_dew_point)].iter() {
+                let hum_ratio_back = psych.get_hum_ratio_from_t_dew_point(t, pressure).unwrap();
+                assert_rel(hum_ratio_back, hum_ratio, 1e-3);
+            }
+
+            // Over ice, the wet bulb is an ice bulb above freezing too
+            let t_wet_bulb = over_ice
+                .get_t_wet_bulb_from_hum_ratio(above, hum_ratio, pressure)
+                .unwrap();
+            let ws_star = over_ice.get_sat_hum_ratio(t_wet_bulb, pressure).unwrap();
+            let ice_bulb_hum_ratio = match units {
+                UnitSystem::SI => {
+                    ((2830.0 - 0.24 * t_wet_bulb) * ws_star - 1.006 * (above - t_wet_bulb))
+                        / (2830.0 + 1.86 * above - 2.1 * t_wet_bulb)
+                }
+                UnitSystem::IP => {
+                    ((1220.0 - 0.04 * t_wet_bulb) * ws_star - 0.240 * (above - t_wet_bulb))
+                        / (1220.0 + 0.444 * above - 0.48 * t_wet_bulb)
+                }
+            };
+            assert_eq!(
+                over_ice.get_hum_ratio_from_t_wet_bulb(above, t_wet_bulb, pressure),
+                Ok(ice_bulb_hum_ratio)
+            );
+            assert_rel(ice_bulb_hum_ratio, hum_ratio, 1e-3);
+        }
+    }
+
+    #[test]
+    fn compile_time_units_match_runtime_units() {
+        let si = Psychrolib::new(Si);
+        let ip: Psychrolib<Ip> = Psychrolib::default();
+        let runtime_si = Psychrolib::new(UnitSystem::SI);
+        let runtime_ip = Psychrolib::new(UnitSystem::IP);
+
+        assert_eq!(si.get_units(), UnitSystem::SI);
+        assert_eq!(ip.get_units(), UnitSystem::IP);
+        assert_eq!(si.get_tolerance(), runtime_si.get_tolerance());
+        assert_eq!(ip.get_tolerance(), runtime_ip.get_tolerance());
+
+        let typed = si
+            .calc_psychrometrics_from_rel_hum(Celsius(25.0), RelHum(0.5), Pascal(101325.0))
+            .unwrap();
+        let runtime = runtime_si
+            .calc_psychrometrics_from_rel_hum(25.0, 0.5, 101325.0)
+            .unwrap();
+        assert_eq!(typed.t_wet_bulb, Celsius(runtime.t_wet_bulb));
+        assert_eq!(typed.hum_ratio, HumRatio(runtime.hum_ratio));
+        assert_eq!(
+            typed.moist_air_enthalpy,
+            JoulePerKg(runtime.moist_air_enthalpy)
+        );
+
+        let typed = ip
+            .calc_psychrometrics_from_rel_hum(Fahrenheit(77.0), RelHum(0.5), Psi(14.696))
+            .unwrap();
+        let runtime = runtime_ip
+            .calc_psychrometrics_from_rel_hum(77.0, 0.5, 14.696)
+            .unwrap();
+        assert_eq!(typed.t_dew_point, Fahrenheit(runtime.t_dew_point));
+        assert_eq!(typed.vap_pres, Psi(runtime.vap_pres));
+        assert_eq!(
+            typed.moist_air_enthalpy,
+            BtuPerLb(runtime.moist_air_enthalpy)
+        );
+    }
+
+    #[test]
+    fn quantity_conversions() {
+        assert_eq!(Fahrenheit::from(Celsius(25.0)), Fahrenheit(77.0));
+        assert_eq!(Celsius::from(Fahrenheit(77.0)), Celsius(25.0));
+        assert!((Pascal::from(Psi(14.696)).0 - 101325.0).abs() < 1.0);
+        assert!((Psi::from(Pascal::from(Psi(14.696))).0 - 14.696).abs() < 1e-12);
+        // Dry air at 0 °C, the reference state of SI enthalpies, has an enthalpy of 7.68 Btu lb⁻¹ in IP
+        assert!((BtuPerLb::from(JoulePerKg(0.0)).0 - 7.68).abs() < 1e-9);
+        let h = JoulePerKg(50000.0);
+        assert!((JoulePerKg::from(BtuPerLb::from(h)).0 - h.0).abs() < 1e-9);
+        assert_eq!(RelHum::from_percent(80.0), RelHum(0.8));
+        assert_eq!(RelHum(0.25).percent(), 25.0);
+        assert_eq!(Celsius(25.0).to_string(), "25 °C");
+        assert_eq!(f64::from(Pascal(101325.0)), 101325.0);
+    }
+
+    #[test]
+    fn pressure_units_match_si_and_ip() {
+        let si = Psychrolib::new(UnitSystem::SI);
+        let ip = Psychrolib::new(UnitSystem::IP);
+
+        // Standard atmosphere at sea level, in each unit
+        let expected = [
+            (PressureUnit::Pa, 101325.0),
+            (PressureUnit::KPa, 101.325),
+            (PressureUnit::HPa, 1013.25),
+            (PressureUnit::Mbar, 1013.25),
+            (PressureUnit::Bar, 1.01325),
+            (PressureUnit::Psi, 14.6959),
+            (PressureUnit::InHg, 29.9213),
+            (PressureUnit::MmHg, 760.0),
+            (PressureUnit::Atm, 1.0),
+        ];
+        for &(unit, pressure) in expected.iter() {
+            assert_rel(si.get_pressure_from_unit(pressure, unit), 101325.0, 1e-5);
+            assert_rel(ip.get_pressure_from_unit(pressure, unit), 14.696, 1e-4);
+            assert_rel(si.get_pressure_in_unit(101325.0, unit), pressure, 1e-5);
+            assert_rel(
+                ip.get_pressure_in_unit(ip.get_pressure_from_unit(pressure, unit), unit),
+                pressure,
+                1e-12,
+            );
+        }
+
+        assert_eq!(
+            PressureUnit::Psi.to_pascal(1.0),
+            f64::from(Pascal::from(Psi(1.0)))
+        );
+        assert_eq!(PressureUnit::InHg.to_string(), "inHg");
+    }
+
+    #[test]
+    fn pressure_units_as_inputs() {
+        let si = Psychrolib::new(UnitSystem::SI);
+        let ip = Psychrolib::new(UnitSystem::IP);
+
+        // Weather station data in hPa and BMS data in inHg give the same humidity ratio
+        let hum_ratio = si.get_hum_ratio_from_rel_hum(25.0, 0.5, 95000.0).unwrap();
+        let pressure_hpa = si.get_pressure_from_unit(950.0, PressureUnit::HPa);
+        let pressure_inhg =
+            ip.get_pressure_from_unit(PressureUnit::InHg.from_pascal(95000.0), PressureUnit::InHg);
+        assert_rel(
+            si.get_hum_ratio_from_rel_hum(25.0, 0.5, pressure_hpa)
+                .unwrap(),
+            hum_ratio,
+            1e-12,
+        );
+        assert_rel(
+            ip.get_hum_ratio_from_rel_hum(77.0, 0.5, pressure_inhg)
+                .unwrap(),
+            hum_ratio,
+            1e-4,
+        );
+
+        let typed = Psychrolib::new(Ip);
+        assert_eq!(
+            typed.get_pressure_from_unit(1.0, PressureUnit::Psi),
+            Psi(1.0)
+        );
+    }
+
+    #[test]
+    fn typed_rel_hum_as_percentage_is_rejected() {
+        let psych = Psychrolib::new(Si);
+
+        assert!(psych
+            .get_hum_ratio_from_rel_hum(Celsius(20.0), RelHum(80.0), Pascal(101325.0))
+            .is_err());
+        assert!(psych
+            .get_hum_ratio_from_rel_hum(Celsius(20.0), RelHum::from_percent(80.0), Pascal(101325.0))
+            .is_ok());
+    }
+
+    #[test]
+    fn moist_air_state_to_units_matches_direct_calculation() {
+        let si = Psychrolib::new(UnitSystem::SI);
+        let ip = Psychrolib::new(UnitSystem::IP);
+
+        let state_si = si
+            .calc_psychrometrics_from_rel_hum(30.0, 0.6, 95461.0)
+            .unwrap();
+        let converted = state_si.to_units(UnitSystem::IP);
+        let state_ip = ip
+            .calc_psychrometrics_from_rel_hum(86.0, 0.6, converted.pressure)
+            .unwrap();
+
+        assert_eq!(converted.units, UnitSystem::IP);
+        assert!((converted.t_dry_bulb - 86.0).abs() < 1e-9);
+        assert!((converted.t_wet_bulb - state_ip.t_wet_bulb).abs() < 0.01);
+        assert!((converted.t_dew_point - state_ip.t_dew_point).abs() < 0.01);
+        assert_rel(converted.vap_pres, state_ip.vap_pres, 1e-4);
+        assert_rel(
+            converted.moist_air_enthalpy,
+            state_ip.moist_air_enthalpy,
+            1e-3,
+        );
+        assert_rel(converted.moist_air_volume, state_ip.moist_air_volume, 1e-4);
+        assert_rel(
+            converted.degree_of_saturation,
+            state_ip.degree_of_saturation,
+            1e-4,
+        );
+
+        // Round trip
+        let back = converted.to_units(UnitSystem::SI);
+        assert_eq!(back.units, UnitSystem::SI);
+        assert_rel(back.t_dry_bulb, state_si.t_dry_bulb, 1e-12);
+        assert_rel(back.pressure, state_si.pressure, 1e-12);
+        assert_rel(back.moist_air_enthalpy, state_si.moist_air_enthalpy, 1e-12);
+        assert_rel(back.moist_air_volume, state_si.moist_air_volume, 1e-12);
+        assert_eq!(state_si.to_units(UnitSystem::SI), state_si);
+    }
+
+    #[test]
+    fn moist_air_state_convert_between_compile_time_units() {
+        let state = Psychrolib::new(Ip)
+            .calc_psychrometrics_from_t_wet_bulb(Fahrenheit(100.0), Fahrenheit(65.0), Psi(14.696))
+            .unwrap();
+        let converted: MoistAirState<Si> = state.convert(Si);
+
+        assert!((converted.t_wet_bulb.0 - 18.333333333333).abs() < 1e-9);
+        assert!((converted.pressure.0 - 101325.0).abs() < 1.0);
+        assert_eq!(converted.rel_hum, state.rel_hum);
+    }
+
+    #[test]
+    fn d_ln_pws_matches_finite_difference() {
+        for psych in [
+            Psychrolib::new(UnitSystem::SI),
+            Psychrolib::new(UnitSystem::IP),
+        ]
+        .iter()
+        {
+            for &t in [-40.0, -1.0, 10.0, 60.0].iter() {
+                let h = 1e-4;
+                let numeric = (psych.get_sat_vap_pres(t + h).unwrap().ln()
+                    - psych.get_sat_vap_pres(t - h).unwrap().ln())
+                    / (2.0 * h);
+                assert!((psych.d_ln_pws(t) - numeric).abs() < 1e-6);
+            }
+        }
     }
 }
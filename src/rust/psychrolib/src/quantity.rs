@@ -0,0 +1,178 @@
+/*
+ * PsychroLib (version 2.5.0) (https://github.com/psychrometrics/psychrolib).
+ * Copyright (c) 2018-2020 The PsychroLib Contributors for the current library implementation.
+ * Copyright (c) 2017 ASHRAE Handbook — Fundamentals for ASHRAE equations and coefficients.
+ * Licensed under the MIT License.
+*/
+
+//! Strongly typed quantities accepted and returned by `Psychrolib<Si>` and `Psychrolib<Ip>`.
+//!
+//! Each quantity is a newtype around an `f64` in a given unit, so that a relative humidity cannot be
+//! passed where a temperature is expected, nor a temperature in °F where one in °C is expected.
+//! Quantities are only made from a number with their tuple constructor, e.g. `Celsius(25.0)`, and
+//! converted to the same quantity in other units with `From`, e.g. `Fahrenheit::from(Celsius(25.0))`.
+//! `Psychrolib<UnitSystem>` keeps working with bare `f64` values.
+
+use std::fmt;
+
+use crate::{
+    BTU_PER_LB_AS_J_PER_KG, ENTHALPY_REFERENCE_OFFSET_IP, FREEZING_POINT_WATER_IP, PSI_AS_PASCAL,
+};
+
+/// Quantity is implemented by the types a Psychrolib accepts and returns for a physical quantity:
+/// the newtypes of this module and `f64` for the unit system chosen at runtime.
+pub trait Quantity:
+    Copy + PartialEq + PartialOrd + fmt::Debug + Into<f64> + sealed::FromF64
+{
+}
+
+impl Quantity for f64 {}
+
+pub(crate) mod sealed {
+    // Makes a quantity from a number in its unit. Only reachable from within the crate, so that a bare
+    // `f64` does not convert into a newtype by mistake.
+    pub trait FromF64 {
+        fn from_f64(value: f64) -> Self;
+    }
+
+    impl FromF64 for f64 {
+        fn from_f64(value: f64) -> f64 {
+            value
+        }
+    }
+}
+
+// Converts a number into the quantity expected by the caller, within the crate.
+pub(crate) trait IntoQuantity {
+    fn into_quantity<Q: Quantity>(self) -> Q;
+}
+
+impl IntoQuantity for f64 {
+    fn into_quantity<Q: Quantity>(self) -> Q {
+        Q::from_f64(self)
+    }
+}
+
+macro_rules! quantity {
+    ($(#[$doc:meta])* $name:ident, $label:expr) => {
+        $(#[$doc])*
+        #[derive(PartialEq, PartialOrd, Debug, Clone, Copy, Default)]
+        pub struct $name(pub f64);
+
+        impl Quantity for $name {}
+
+        impl sealed::FromF64 for $name {
+            fn from_f64(value: f64) -> $name {
+                $name(value)
+            }
+        }
+
+        impl From<$name> for f64 {
+            fn from(quantity: $name) -> f64 {
+                quantity.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "{}{}", self.0, $label)
+            }
+        }
+    };
+}
+
+quantity!(
+    /// Temperature in degree Celsius (°C)
+    Celsius,
+    " °C"
+);
+quantity!(
+    /// Temperature in degree Fahrenheit (°F)
+    Fahrenheit,
+    " °F"
+);
+quantity!(
+    /// Pressure in Pascal (Pa)
+    Pascal,
+    " Pa"
+);
+quantity!(
+    /// Pressure in pound-force per square inch (Psi)
+    Psi,
+    " Psi"
+);
+quantity!(
+    /// Specific enthalpy in J kg⁻¹
+    JoulePerKg,
+    " J kg⁻¹"
+);
+quantity!(
+    /// Specific enthalpy in Btu lb⁻¹
+    BtuPerLb,
+    " Btu lb⁻¹"
+);
+quantity!(
+    /// Humidity ratio in kg_H₂O kg_Air⁻¹ [SI] or lb_H₂O lb_Air⁻¹ [IP], which are the same ratio
+    HumRatio,
+    ""
+);
+quantity!(
+    /// Relative humidity as a fraction in range [0, 1], not a percentage
+    RelHum,
+    ""
+);
+
+impl From<Celsius> for Fahrenheit {
+    fn from(t: Celsius) -> Fahrenheit {
+        Fahrenheit(t.0 * 9.0 / 5.0 + FREEZING_POINT_WATER_IP)
+    }
+}
+
+impl From<Fahrenheit> for Celsius {
+    fn from(t: Fahrenheit) -> Celsius {
+        Celsius((t.0 - FREEZING_POINT_WATER_IP) * 5.0 / 9.0)
+    }
+}
+
+impl From<Psi> for Pascal {
+    fn from(p: Psi) -> Pascal {
+        Pascal(p.0 * PSI_AS_PASCAL)
+    }
+}
+
+impl From<Pascal> for Psi {
+    fn from(p: Pascal) -> Psi {
+        Psi(p.0 / PSI_AS_PASCAL)
+    }
+}
+
+// Moist air enthalpies also shift between the reference states of the SI equations (dry air at 0 °C) and of the
+// IP equations (dry air at 0 °F).
+impl From<JoulePerKg> for BtuPerLb {
+    fn from(h: JoulePerKg) -> BtuPerLb {
+        BtuPerLb(h.0 / BTU_PER_LB_AS_J_PER_KG + ENTHALPY_REFERENCE_OFFSET_IP)
+    }
+}
+
+impl From<BtuPerLb> for JoulePerKg {
+    fn from(h: BtuPerLb) -> JoulePerKg {
+        JoulePerKg((h.0 - ENTHALPY_REFERENCE_OFFSET_IP) * BTU_PER_LB_AS_J_PER_KG)
+    }
+}
+
+impl RelHum {
+    /// Returns the relative humidity given as a percentage in range [0, 100]
+    ///
+    /// # Example
+    ///     use psychrolib::RelHum;
+    ///
+    ///     assert_eq!(RelHum::from_percent(80.0), RelHum(0.8));
+    pub fn from_percent(percent: f64) -> RelHum {
+        RelHum(percent / 100.0)
+    }
+
+    /// Returns the relative humidity as a percentage in range [0, 100]
+    pub fn percent(self) -> f64 {
+        self.0 * 100.0
+    }
+}
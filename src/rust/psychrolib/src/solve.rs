@@ -0,0 +1,299 @@
+/*
+ * PsychroLib (version 2.5.0) (https://github.com/psychrometrics/psychrolib).
+ * Copyright (c) 2018-2020 The PsychroLib Contributors for the current library implementation.
+ * Copyright (c) 2017 ASHRAE Handbook — Fundamentals for ASHRAE equations and coefficients.
+ * Licensed under the MIT License.
+*/
+
+//! Root finders for inverse problems the library does not provide, e.g. the dry-bulb temperature giving
+//! a target enthalpy at a fixed relative humidity.
+//!
+//! Each root finder solves `f(x) = 0` for a closure `f`, which may call any Psychrolib function and
+//! propagate its errors with `?`. The tolerance on `x` and the max number of iterations are those of the
+//! Psychrolib passed along, see Psychrolib::with_tolerance and Psychrolib::with_max_iter_count.
+//!
+//! # Example
+//!     use psychrolib::{solve, Psychrolib, UnitSystem};
+//!
+//!     let psych = Psychrolib::new(UnitSystem::SI);
+//!
+//!     // Dry-bulb temperature of air at 50 % relative humidity with an enthalpy of 50 kJ kg⁻¹
+//!     let enthalpy_at = |t_dry_bulb: f64| {
+//!         let hum_ratio = psych.get_hum_ratio_from_rel_hum(t_dry_bulb, 0.5, 101325.0)?;
+//!         psych.get_moist_air_enthalpy(t_dry_bulb, hum_ratio)
+//!     };
+//!     let t_dry_bulb = solve::brent(&psych, |t| Ok(enthalpy_at(t)? - 50000.0), 0.0, 40.0).unwrap();
+//!
+//!     assert!((enthalpy_at(t_dry_bulb).unwrap() - 50000.0).abs() < 10.0);
+
+use crate::{PsychroError, Psychrolib, SolverReport, Units};
+
+/// Returns a root of `f` between `lower` and `upper` found by bisection, within the tolerance of `psych`.
+///
+/// `f` must have opposite signs at `lower` and `upper`, or be 0 at one of them. NaN at either gives NaN.
+pub fn bisection<U, F>(
+    psych: &Psychrolib<U>,
+    mut f: F,
+    lower: f64,
+    upper: f64,
+) -> Result<f64, PsychroError>
+where
+    U: Units,
+    F: FnMut(f64) -> Result<f64, PsychroError>,
+{
+    let (mut lower, mut upper) = (lower, upper);
+    let f_lower = f(lower)?;
+    let f_upper = f(upper)?;
+    if let Some(root) = check_bracket("solve::bisection", lower, upper, f_lower, f_upper)? {
+        return Ok(root);
+    }
+
+    let is_lower_positive = f_lower > 0.0;
+    let mut index = 1;
+    while (upper - lower).abs() > psych.get_tolerance() {
+        let x = (lower + upper) / 2.0;
+        if (f(x)? > 0.0) == is_lower_positive {
+            lower = x;
+        } else {
+            upper = x;
+        }
+
+        if index >= psych.config.max_iter_count {
+            return Err(psych.convergence_not_reached("solve::bisection"));
+        }
+
+        index += 1;
+    }
+
+    Ok((lower + upper) / 2.0)
+}
+
+/// Returns a root of `f` between `lower` and `upper` found by Brent's method, within the tolerance of `psych`.
+/// Brent's method keeps the root bracketed like bisection, but usually needs far fewer iterations.
+///
+/// `f` must have opposite signs at `lower` and `upper`, or be 0 at one of them. NaN at either gives NaN.
+///
+/// Reference: Brent RP, Algorithms for minimization without derivatives, Prentice-Hall 1973, ch. 4
+pub fn brent<U, F>(
+    psych: &Psychrolib<U>,
+    mut f: F,
+    lower: f64,
+    upper: f64,
+) -> Result<f64, PsychroError>
+where
+    U: Units,
+    F: FnMut(f64) -> Result<f64, PsychroError>,
+{
+    let f_lower = f(lower)?;
+    let f_upper = f(upper)?;
+    if let Some(root) = check_bracket("solve::brent", lower, upper, f_lower, f_upper)? {
+        return Ok(root);
+    }
+
+    let (root, _, converged) =
+        brent_bracketed(psych, &mut f, [lower, upper], [f_lower, f_upper], 1, None)?;
+    if !converged {
+        return Err(psych.convergence_not_reached("solve::brent"));
+    }
+
+    Ok(root)
+}
+
+/// Returns a root of `f` found by the Newton-Raphson method from the first guess `x0`, once a step is within
+/// the tolerance of `psych`. `f` returns the value of the function and of its derivative.
+///
+/// Newton-Raphson converges fastest from a good first guess, but is not guaranteed to converge. A
+/// PsychroError::ZeroDerivative is returned when the derivative is 0 or not finite at an iterate.
+pub fn newton<U, F>(psych: &Psychrolib<U>, mut f: F, x0: f64) -> Result<f64, PsychroError>
+where
+    U: Units,
+    F: FnMut(f64) -> Result<(f64, f64), PsychroError>,
+{
+    let mut x = x0;
+    for iterations in 0..psych.config.max_iter_count {
+        let (f_x, d_f_x) = f(x)?;
+        if f_x.is_nan() {
+            return Ok(f64::NAN);
+        }
+
+        let step = f_x / d_f_x;
+        if !step.is_finite() {
+            return Err(PsychroError::ZeroDerivative {
+                function: "solve::newton",
+                x,
+                iterations,
+            });
+        }
+        x -= step;
+
+        if step.abs() <= psych.get_tolerance() {
+            return Ok(x);
+        }
+    }
+
+    Err(psych.convergence_not_reached("solve::newton"))
+}
+
+/// Returns a root of `f` found by the secant method from the first guesses `x0` and `x1`, once a step is within
+/// the tolerance of `psych`. The secant method needs no derivative, but is not guaranteed to converge. A
+/// PsychroError::ZeroDerivative is returned when `f` has the same value at two successive iterates.
+pub fn secant<U, F>(psych: &Psychrolib<U>, mut f: F, x0: f64, x1: f64) -> Result<f64, PsychroError>
+where
+    U: Units,
+    F: FnMut(f64) -> Result<f64, PsychroError>,
+{
+    let (mut x0, mut x1) = (x0, x1);
+    let mut f_x0 = f(x0)?;
+    for iterations in 0..psych.config.max_iter_count {
+        let f_x1 = f(x1)?;
+        if f_x0.is_nan() || f_x1.is_nan() {
+            return Ok(f64::NAN);
+        }
+
+        let step = f_x1 * (x1 - x0) / (f_x1 - f_x0);
+        if !step.is_finite() {
+            return Err(PsychroError::ZeroDerivative {
+                function: "solve::secant",
+                x: x1,
+                iterations,
+            });
+        }
+        x0 = x1;
+        f_x0 = f_x1;
+        x1 -= step;
+
+        if step.abs() <= psych.get_tolerance() {
+            return Ok(x1);
+        }
+    }
+
+    Err(psych.convergence_not_reached("solve::secant"))
+}
+
+// Checks that a function changes sign between `lower` and `upper`. Returns the root if it is at a bound,
+// or NaN if the function is NaN at a bound.
+fn check_bracket(
+    function: &'static str,
+    lower: f64,
+    upper: f64,
+    f_lower: f64,
+    f_upper: f64,
+) -> Result<Option<f64>, PsychroError> {
+    if f_lower.is_nan() || f_upper.is_nan() {
+        Ok(Some(f64::NAN))
+    } else if f_lower == 0.0 {
+        Ok(Some(lower))
+    } else if f_upper == 0.0 {
+        Ok(Some(upper))
+    } else if (f_lower > 0.0) == (f_upper > 0.0) {
+        Err(PsychroError::RootNotBracketed {
+            function,
+            lower,
+            upper,
+        })
+    } else {
+        Ok(None)
+    }
+}
+
+// Finds a root of `f` within `bracket` by Brent's method, given the values `f_bracket` of `f` at the bounds,
+// of which exactly one is positive, and counting iterations from `index`. Values of `f` of 0 count as negative.
+// Returns the estimate, the final bracket, and whether the bracket shrank to the tolerance of `psych` within
+// its max number of iterations, and records the iterations in `report`.
+//
+// Reference: Press WH et al., Numerical Recipes, 3rd edition, Cambridge University Press 2007, ch. 9.3
+pub(crate) fn brent_bracketed<U: Units>(
+    psych: &Psychrolib<U>,
+    f: &mut dyn FnMut(f64) -> Result<f64, PsychroError>,
+    bracket: [f64; 2],
+    f_bracket: [f64; 2],
+    mut index: usize,
+    mut report: Option<&mut SolverReport>,
+) -> Result<(f64, [f64; 2], bool), PsychroError> {
+    let [mut a, mut b] = bracket;
+    let [mut fa, mut fb] = f_bracket;
+    // b is the best estimate, and the solution lies between b and c
+    let (mut c, mut fc) = (a, fa);
+    let mut d = b - a;
+    let mut e = d;
+
+    loop {
+        if (fb > 0.0) == (fc > 0.0) {
+            c = a;
+            fc = fa;
+            d = b - a;
+            e = d;
+        }
+        if fc.abs() < fb.abs() {
+            a = b;
+            b = c;
+            c = a;
+            fa = fb;
+            fb = fc;
+            fc = fa;
+        }
+
+        let tol = 2.0 * f64::EPSILON * b.abs() + 0.5 * psych.get_tolerance();
+        let xm = 0.5 * (c - b);
+        if xm.abs() <= tol {
+            return Ok((b, [b.min(c), b.max(c)], true));
+        }
+
+        if e.abs() >= tol && fa.abs() > fb.abs() {
+            // Attempt inverse quadratic interpolation, or the secant method with two points
+            let s = fb / fa;
+            let (mut p, mut q);
+            if a == c {
+                p = 2.0 * xm * s;
+                q = 1.0 - s;
+            } else {
+                let r = fb / fc;
+                q = fa / fc;
+                p = s * (2.0 * xm * q * (q - r) - (b - a) * (r - 1.0));
+                q = (q - 1.0) * (r - 1.0) * (s - 1.0);
+            }
+            if p > 0.0 {
+                q = -q;
+            }
+            p = p.abs();
+
+            // Accept the interpolation only if it falls within the bracket and converges fast enough
+            if 2.0 * p < (3.0 * xm * q - (tol * q).abs()).min((e * q).abs()) {
+                e = d;
+                d = p / q;
+            } else {
+                d = xm;
+                e = d;
+            }
+        } else {
+            // Bisection step
+            d = xm;
+            e = d;
+        }
+
+        a = b;
+        fa = fb;
+        b += if d.abs() > tol { d } else { tol.copysign(xm) };
+        fb = f(b)?;
+
+        if let Some(report) = report.as_deref_mut() {
+            let (lower, upper) = if (fb > 0.0) == (fc > 0.0) {
+                (a.min(b), a.max(b))
+            } else {
+                (b.min(c), b.max(c))
+            };
+            report.record(index, b, lower, upper);
+        }
+
+        if index >= psych.config.max_iter_count {
+            let bracket = if (fb > 0.0) == (fc > 0.0) {
+                [a.min(b), a.max(b)]
+            } else {
+                [b.min(c), b.max(c)]
+            };
+            return Ok((b, bracket, false));
+        }
+
+        index += 1;
+    }
+}
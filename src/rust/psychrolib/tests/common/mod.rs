@@ -0,0 +1,30 @@
+/*
+ * PsychroLib (version 2.5.0) (https://github.com/psychrometrics/psychrolib).
+ * Copyright (c) 2018-2020 The PsychroLib Contributors. Licensed under the MIT License.
+*/
+
+// Helpers shared by the integration tests, mirroring pytest.approx.
+
+// Asserts that `actual` is within a relative tolerance `rel` of `expected`.
+#[allow(dead_code)]
+pub fn assert_rel(actual: f64, expected: f64, rel: f64) {
+    assert!(
+        (actual - expected).abs() <= (rel * expected.abs()).max(1e-12),
+        "{} != {} (rel = {})",
+        actual,
+        expected,
+        rel
+    );
+}
+
+// Asserts that `actual` is within an absolute tolerance `abs` of `expected`.
+#[allow(dead_code)]
+pub fn assert_abs(actual: f64, expected: f64, abs: f64) {
+    assert!(
+        (actual - expected).abs() <= abs,
+        "{} != {} (abs = {})",
+        actual,
+        expected,
+        abs
+    );
+}
@@ -0,0 +1,714 @@
+/*
+ * PsychroLib (version 2.5.0) (https://github.com/psychrometrics/psychrolib).
+ * Copyright (c) 2018-2020 The PsychroLib Contributors. Licensed under the MIT License.
+*/
+
+// Differential tests of the Rust port against the C implementation in src/c.
+// The C library is compiled by build.rs; these tests are skipped when it could not be built.
+//
+// The C library asserts (and exits) on invalid inputs, so each function is only called
+// from C when the Rust port accepted the inputs. The unit system of the C library is a
+// global, hence all tests hold a lock while calling into it.
+
+#![cfg(psychrolib_c)]
+
+use psychrolib::{Property, PsychroError, Psychrolib, UnitSystem};
+use std::os::raw::c_int;
+use std::sync::Mutex;
+
+const C_IP: c_int = 1;
+const C_SI: c_int = 2;
+
+#[allow(non_snake_case)]
+extern "C" {
+    fn SetUnitSystem(Units: c_int);
+
+    fn GetTWetBulbFromTDewPoint(TDryBulb: f64, TDewPoint: f64, Pressure: f64) -> f64;
+    fn GetTWetBulbFromRelHum(TDryBulb: f64, RelHum: f64, Pressure: f64) -> f64;
+    fn GetRelHumFromTDewPoint(TDryBulb: f64, TDewPoint: f64) -> f64;
+    fn GetRelHumFromTWetBulb(TDryBulb: f64, TWetBulb: f64, Pressure: f64) -> f64;
+    fn GetTDewPointFromRelHum(TDryBulb: f64, RelHum: f64) -> f64;
+    fn GetTDewPointFromTWetBulb(TDryBulb: f64, TWetBulb: f64, Pressure: f64) -> f64;
+
+    fn GetVapPresFromRelHum(TDryBulb: f64, RelHum: f64) -> f64;
+    fn GetRelHumFromVapPres(TDryBulb: f64, VapPres: f64) -> f64;
+    fn GetTDewPointFromVapPres(TDryBulb: f64, VapPres: f64) -> f64;
+    fn GetVapPresFromTDewPoint(TDewPoint: f64) -> f64;
+
+    fn GetTWetBulbFromHumRatio(TDryBulb: f64, HumRatio: f64, Pressure: f64) -> f64;
+    fn GetHumRatioFromTWetBulb(TDryBulb: f64, TWetBulb: f64, Pressure: f64) -> f64;
+    fn GetHumRatioFromRelHum(TDryBulb: f64, RelHum: f64, Pressure: f64) -> f64;
+    fn GetRelHumFromHumRatio(TDryBulb: f64, HumRatio: f64, Pressure: f64) -> f64;
+    fn GetHumRatioFromTDewPoint(TDewPoint: f64, Pressure: f64) -> f64;
+    fn GetTDewPointFromHumRatio(TDryBulb: f64, HumRatio: f64, Pressure: f64) -> f64;
+
+    fn GetHumRatioFromVapPres(VapPres: f64, Pressure: f64) -> f64;
+    fn GetVapPresFromHumRatio(HumRatio: f64, Pressure: f64) -> f64;
+
+    fn GetSpecificHumFromHumRatio(HumRatio: f64) -> f64;
+    fn GetHumRatioFromSpecificHum(SpecificHum: f64) -> f64;
+
+    fn GetDryAirEnthalpy(TDryBulb: f64) -> f64;
+    fn GetDryAirDensity(TDryBulb: f64, Pressure: f64) -> f64;
+    fn GetDryAirVolume(TDryBulb: f64, Pressure: f64) -> f64;
+    fn GetTDryBulbFromEnthalpyAndHumRatio(MoistAirEnthalpy: f64, HumRatio: f64) -> f64;
+    fn GetHumRatioFromEnthalpyAndTDryBulb(MoistAirEnthalpy: f64, TDryBulb: f64) -> f64;
+
+    fn GetSatVapPres(TDryBulb: f64) -> f64;
+    fn GetSatHumRatio(TDryBulb: f64, Pressure: f64) -> f64;
+    fn GetSatAirEnthalpy(TDryBulb: f64, Pressure: f64) -> f64;
+
+    fn GetVaporPressureDeficit(TDryBulb: f64, HumRatio: f64, Pressure: f64) -> f64;
+    fn GetDegreeOfSaturation(TDryBulb: f64, HumRatio: f64, Pressure: f64) -> f64;
+    fn GetMoistAirEnthalpy(TDryBulb: f64, HumRatio: f64) -> f64;
+    fn GetMoistAirVolume(TDryBulb: f64, HumRatio: f64, Pressure: f64) -> f64;
+    fn GetTDryBulbFromMoistAirVolumeAndHumRatio(
+        MoistAirVolume: f64,
+        HumRatio: f64,
+        Pressure: f64,
+    ) -> f64;
+    fn GetMoistAirDensity(TDryBulb: f64, HumRatio: f64, Pressure: f64) -> f64;
+
+    fn GetStandardAtmPressure(Altitude: f64) -> f64;
+    fn GetStandardAtmTemperature(Altitude: f64) -> f64;
+    fn GetSeaLevelPressure(StnPressure: f64, Altitude: f64, TDryBulb: f64) -> f64;
+    fn GetStationPressure(SeaLevelPressure: f64, Altitude: f64, TDryBulb: f64) -> f64;
+
+    fn CalcPsychrometricsFromTWetBulb(
+        TDryBulb: f64,
+        TWetBulb: f64,
+        Pressure: f64,
+        HumRatio: *mut f64,
+        TDewPoint: *mut f64,
+        RelHum: *mut f64,
+        VapPres: *mut f64,
+        MoistAirEnthalpy: *mut f64,
+        MoistAirVolume: *mut f64,
+        DegreeOfSaturation: *mut f64,
+    );
+    fn CalcPsychrometricsFromTDewPoint(
+        TDryBulb: f64,
+        TDewPoint: f64,
+        Pressure: f64,
+        HumRatio: *mut f64,
+        TWetBulb: *mut f64,
+        RelHum: *mut f64,
+        VapPres: *mut f64,
+        MoistAirEnthalpy: *mut f64,
+        MoistAirVolume: *mut f64,
+        DegreeOfSaturation: *mut f64,
+    );
+    fn CalcPsychrometricsFromRelHum(
+        TDryBulb: f64,
+        RelHum: f64,
+        Pressure: f64,
+        HumRatio: *mut f64,
+        TWetBulb: *mut f64,
+        TDewPoint: *mut f64,
+        VapPres: *mut f64,
+        MoistAirEnthalpy: *mut f64,
+        MoistAirVolume: *mut f64,
+        DegreeOfSaturation: *mut f64,
+    );
+}
+
+// Guards the global unit system of the C library.
+static C_UNIT_SYSTEM: Mutex<()> = Mutex::new(());
+
+// Tolerances for closed-form functions, which implement the same equations in both languages.
+// The absolute tolerance covers round-off near zero (e.g. at saturation or at MIN_HUM_RATIO).
+const REL_TOLERANCE: f64 = 1e-8;
+const ABS_TOLERANCE: f64 = 1e-12;
+
+/// Sweep of dry bulb temperature, relative humidity and pressure for one unit system.
+struct Grid {
+    units: UnitSystem,
+    t_dry_bulb: Vec<f64>,
+    rel_hum: Vec<f64>,
+    pressure: Vec<f64>,
+    altitude: Vec<f64>,
+}
+
+impl Grid {
+    fn new(units: UnitSystem) -> Grid {
+        let rel_hum = (0..=20).map(|i| i as f64 * 0.05).collect();
+        match units {
+            UnitSystem::IP => Grid {
+                units,
+                t_dry_bulb: (0..=120).map(|i| -148.0 + i as f64 * 4.5).collect(),
+                rel_hum,
+                pressure: (0..=11).map(|i| 8.6 + i as f64 * 0.8).collect(),
+                altitude: (0..=32).map(|i| -1000.0 + i as f64 * 1000.0).collect(),
+            },
+            UnitSystem::SI => Grid {
+                units,
+                t_dry_bulb: (0..=120).map(|i| -100.0 + i as f64 * 2.5).collect(),
+                rel_hum,
+                pressure: (0..=12).map(|i| 60000.0 + i as f64 * 5000.0).collect(),
+                altitude: (0..=20).map(|i| -500.0 + i as f64 * 500.0).collect(),
+            },
+        }
+    }
+
+    fn c_units(&self) -> c_int {
+        match self.units {
+            UnitSystem::IP => C_IP,
+            UnitSystem::SI => C_SI,
+        }
+    }
+
+    /// Calls `f` for every (dry bulb, relative humidity, pressure) triple of the grid,
+    /// with the C library set to the same unit system.
+    fn sweep<F: FnMut(&mut Comparison, f64, f64, f64)>(&self, mut f: F) {
+        let _guard = C_UNIT_SYSTEM.lock().unwrap_or_else(|e| e.into_inner());
+        unsafe { SetUnitSystem(self.c_units()) };
+
+        let mut comparison = Comparison::new(self.units);
+        for &t_dry_bulb in &self.t_dry_bulb {
+            for &rel_hum in &self.rel_hum {
+                for &pressure in &self.pressure {
+                    f(&mut comparison, t_dry_bulb, rel_hum, pressure);
+                }
+            }
+        }
+        assert!(comparison.count > 0, "no inputs were compared against C");
+    }
+}
+
+// Returns whether the Rust port rejects inputs of the grid as C does: partial pressures of water vapor below
+// the saturation vapor pressure at the lowest temperature, e.g. at a relative humidity of 0, are outside the
+// range of the dew-point solver in both.
+fn is_expected_rejection(error: &PsychroError) -> bool {
+    match *error {
+        PsychroError::OutOfRange {
+            property: Property::VapPres,
+            value,
+            min,
+            ..
+        } => value < min,
+        _ => false,
+    }
+}
+
+/// Compares results of the Rust port with those of the C library.
+struct Comparison {
+    psy: Psychrolib,
+    count: usize,
+}
+
+impl Comparison {
+    fn new(units: UnitSystem) -> Comparison {
+        Comparison {
+            psy: Psychrolib::new(units),
+            count: 0,
+        }
+    }
+
+    // Iterative solutions agree to within the solver tolerance.
+    fn abs_tolerance(&self) -> f64 {
+        self.psy.get_tolerance()
+    }
+
+    /// Returns the result of the Rust port, or None if it rejected the inputs. Only the rejections C makes
+    /// too are expected, any other fails the test.
+    fn accept<T>(
+        &mut self,
+        function: &str,
+        inputs: &[f64],
+        rust: Result<T, PsychroError>,
+    ) -> Option<T> {
+        match rust {
+            Ok(rust) => Some(rust),
+            Err(error) => {
+                assert!(
+                    is_expected_rejection(&error),
+                    "{}{:?} ({:?}): Rust rejected the inputs: {}",
+                    function,
+                    inputs,
+                    self.psy.get_units(),
+                    error
+                );
+                None
+            }
+        }
+    }
+
+    /// Checks `rust` against `c` to within `abs` or `rel` relative to the C value, whichever is larger.
+    /// Inputs rejected by the Rust port are not passed to C, which would exit the process.
+    fn check<C: FnOnce() -> f64>(
+        &mut self,
+        function: &str,
+        inputs: &[f64],
+        rust: Result<f64, PsychroError>,
+        c: C,
+        abs: f64,
+        rel: f64,
+    ) -> Option<f64> {
+        let rust = self.accept(function, inputs, rust)?;
+        let c = c();
+        let tolerance = abs.max(rel * c.abs());
+        assert!(
+            (rust - c).abs() <= tolerance,
+            "{}{:?} ({:?}): Rust returned {}, C returned {} (tolerance {})",
+            function,
+            inputs,
+            self.psy.get_units(),
+            rust,
+            c,
+            tolerance
+        );
+        self.count += 1;
+        Some(rust)
+    }
+
+    fn check_rel<C: FnOnce() -> f64>(
+        &mut self,
+        function: &str,
+        inputs: &[f64],
+        rust: Result<f64, PsychroError>,
+        c: C,
+    ) -> Option<f64> {
+        self.check(function, inputs, rust, c, ABS_TOLERANCE, REL_TOLERANCE)
+    }
+
+    fn check_abs<C: FnOnce() -> f64>(
+        &mut self,
+        function: &str,
+        inputs: &[f64],
+        rust: Result<f64, PsychroError>,
+        c: C,
+    ) -> Option<f64> {
+        let abs = self.abs_tolerance();
+        self.check(function, inputs, rust, c, abs, REL_TOLERANCE)
+    }
+}
+
+fn both_unit_systems<F: Fn(&Grid)>(f: F) {
+    f(&Grid::new(UnitSystem::IP));
+    f(&Grid::new(UnitSystem::SI));
+}
+
+/******************************************************************************************************
+ * Conversions between dew point, wet bulb, relative humidity and vapor pressure
+ *****************************************************************************************************/
+
+#[test]
+fn vap_pres_and_rel_hum_match_c() {
+    both_unit_systems(|grid| {
+        grid.sweep(|cmp, t, rh, _| {
+            let rust = cmp.psy.get_vap_pres_from_rel_hum(t, rh);
+            let vp = cmp.check_rel("GetVapPresFromRelHum", &[t, rh], rust, || unsafe {
+                GetVapPresFromRelHum(t, rh)
+            });
+            if let Some(vp) = vp {
+                let rust = cmp.psy.get_rel_hum_from_vap_pres(t, vp);
+                cmp.check_rel("GetRelHumFromVapPres", &[t, vp], rust, || unsafe {
+                    GetRelHumFromVapPres(t, vp)
+                });
+            }
+        });
+    });
+}
+
+#[test]
+fn t_dew_point_and_vap_pres_match_c() {
+    both_unit_systems(|grid| {
+        grid.sweep(|cmp, t, rh, _| {
+            let rust = cmp.psy.get_vap_pres_from_rel_hum(t, rh);
+            let vp = match cmp.accept("GetVapPresFromRelHum", &[t, rh], rust) {
+                Some(vp) => vp,
+                None => return,
+            };
+            let rust = cmp.psy.get_t_dew_point_from_vap_pres(t, vp);
+            let td = cmp.check_abs("GetTDewPointFromVapPres", &[t, vp], rust, || unsafe {
+                GetTDewPointFromVapPres(t, vp)
+            });
+            if let Some(td) = td {
+                let rust = cmp.psy.get_vap_pres_from_t_dew_point(td);
+                cmp.check_rel("GetVapPresFromTDewPoint", &[td], rust, || unsafe {
+                    GetVapPresFromTDewPoint(td)
+                });
+            }
+        });
+    });
+}
+
+#[test]
+fn t_dew_point_and_rel_hum_match_c() {
+    both_unit_systems(|grid| {
+        grid.sweep(|cmp, t, rh, _| {
+            let rust = cmp.psy.get_t_dew_point_from_rel_hum(t, rh);
+            let td = cmp.check_abs("GetTDewPointFromRelHum", &[t, rh], rust, || unsafe {
+                GetTDewPointFromRelHum(t, rh)
+            });
+            if let Some(td) = td {
+                let rust = cmp.psy.get_rel_hum_from_t_dew_point(t, td);
+                cmp.check_rel("GetRelHumFromTDewPoint", &[t, td], rust, || unsafe {
+                    GetRelHumFromTDewPoint(t, td)
+                });
+            }
+        });
+    });
+}
+
+#[test]
+fn t_wet_bulb_and_rel_hum_match_c() {
+    both_unit_systems(|grid| {
+        grid.sweep(|cmp, t, rh, p| {
+            let rust = cmp.psy.get_t_wet_bulb_from_rel_hum(t, rh, p);
+            let tw = cmp.check_abs("GetTWetBulbFromRelHum", &[t, rh, p], rust, || unsafe {
+                GetTWetBulbFromRelHum(t, rh, p)
+            });
+            if let Some(tw) = tw {
+                let rust = cmp.psy.get_rel_hum_from_t_wet_bulb(t, tw, p);
+                cmp.check_rel("GetRelHumFromTWetBulb", &[t, tw, p], rust, || unsafe {
+                    GetRelHumFromTWetBulb(t, tw, p)
+                });
+            }
+        });
+    });
+}
+
+#[test]
+fn t_wet_bulb_and_t_dew_point_match_c() {
+    both_unit_systems(|grid| {
+        grid.sweep(|cmp, t, rh, p| {
+            let rust = cmp.psy.get_t_dew_point_from_rel_hum(t, rh);
+            let td = match cmp.accept("GetTDewPointFromRelHum", &[t, rh], rust) {
+                Some(td) => td,
+                None => return,
+            };
+            let rust = cmp.psy.get_t_wet_bulb_from_t_dew_point(t, td, p);
+            let tw = cmp.check_abs("GetTWetBulbFromTDewPoint", &[t, td, p], rust, || unsafe {
+                GetTWetBulbFromTDewPoint(t, td, p)
+            });
+            if let Some(tw) = tw {
+                let rust = cmp.psy.get_t_dew_point_from_t_wet_bulb(t, tw, p);
+                cmp.check_abs("GetTDewPointFromTWetBulb", &[t, tw, p], rust, || unsafe {
+                    GetTDewPointFromTWetBulb(t, tw, p)
+                });
+            }
+        });
+    });
+}
+
+/******************************************************************************************************
+ * Conversions from and to humidity ratio
+ *****************************************************************************************************/
+
+#[test]
+fn hum_ratio_and_rel_hum_match_c() {
+    both_unit_systems(|grid| {
+        grid.sweep(|cmp, t, rh, p| {
+            let rust = cmp.psy.get_hum_ratio_from_rel_hum(t, rh, p);
+            let w = cmp.check_rel("GetHumRatioFromRelHum", &[t, rh, p], rust, || unsafe {
+                GetHumRatioFromRelHum(t, rh, p)
+            });
+            if let Some(w) = w {
+                let rust = cmp.psy.get_rel_hum_from_hum_ratio(t, w, p);
+                cmp.check_rel("GetRelHumFromHumRatio", &[t, w, p], rust, || unsafe {
+                    GetRelHumFromHumRatio(t, w, p)
+                });
+            }
+        });
+    });
+}
+
+#[test]
+fn hum_ratio_and_t_dew_point_match_c() {
+    both_unit_systems(|grid| {
+        grid.sweep(|cmp, t, rh, p| {
+            let rust = cmp.psy.get_hum_ratio_from_rel_hum(t, rh, p);
+            let w = match cmp.accept("GetHumRatioFromRelHum", &[t, rh, p], rust) {
+                Some(w) => w,
+                None => return,
+            };
+            let rust = cmp.psy.get_t_dew_point_from_hum_ratio(t, w, p);
+            let td = cmp.check_abs("GetTDewPointFromHumRatio", &[t, w, p], rust, || unsafe {
+                GetTDewPointFromHumRatio(t, w, p)
+            });
+            if let Some(td) = td {
+                let rust = cmp.psy.get_hum_ratio_from_t_dew_point(td, p);
+                cmp.check_rel("GetHumRatioFromTDewPoint", &[td, p], rust, || unsafe {
+                    GetHumRatioFromTDewPoint(td, p)
+                });
+            }
+        });
+    });
+}
+
+#[test]
+fn hum_ratio_and_t_wet_bulb_match_c() {
+    both_unit_systems(|grid| {
+        grid.sweep(|cmp, t, rh, p| {
+            let rust = cmp.psy.get_hum_ratio_from_rel_hum(t, rh, p);
+            let w = match cmp.accept("GetHumRatioFromRelHum", &[t, rh, p], rust) {
+                Some(w) => w,
+                None => return,
+            };
+            let rust = cmp.psy.get_t_wet_bulb_from_hum_ratio(t, w, p);
+            let tw = cmp.check_abs("GetTWetBulbFromHumRatio", &[t, w, p], rust, || unsafe {
+                GetTWetBulbFromHumRatio(t, w, p)
+            });
+            if let Some(tw) = tw {
+                let rust = cmp.psy.get_hum_ratio_from_t_wet_bulb(t, tw, p);
+                cmp.check_rel("GetHumRatioFromTWetBulb", &[t, tw, p], rust, || unsafe {
+                    GetHumRatioFromTWetBulb(t, tw, p)
+                });
+            }
+        });
+    });
+}
+
+#[test]
+fn hum_ratio_vap_pres_and_specific_hum_match_c() {
+    both_unit_systems(|grid| {
+        grid.sweep(|cmp, t, rh, p| {
+            let vp = match cmp.psy.get_vap_pres_from_rel_hum(t, rh) {
+                Ok(vp) if vp < p => vp,
+                _ => return,
+            };
+            let rust = cmp.psy.get_hum_ratio_from_vap_pres(vp, p);
+            let w = cmp.check_rel("GetHumRatioFromVapPres", &[vp, p], rust, || unsafe {
+                GetHumRatioFromVapPres(vp, p)
+            });
+            if let Some(w) = w {
+                let rust = cmp.psy.get_vap_pres_from_hum_ratio(w, p);
+                cmp.check_rel("GetVapPresFromHumRatio", &[w, p], rust, || unsafe {
+                    GetVapPresFromHumRatio(w, p)
+                });
+                let rust = cmp.psy.get_specific_hum_from_hum_ratio(w);
+                let q = cmp.check_rel("GetSpecificHumFromHumRatio", &[w], rust, || unsafe {
+                    GetSpecificHumFromHumRatio(w)
+                });
+                if let Some(q) = q {
+                    let rust = cmp.psy.get_hum_ratio_from_specific_hum(q);
+                    cmp.check_rel("GetHumRatioFromSpecificHum", &[q], rust, || unsafe {
+                        GetHumRatioFromSpecificHum(q)
+                    });
+                }
+            }
+        });
+    });
+}
+
+/******************************************************************************************************
+ * Dry, saturated and moist air
+ *****************************************************************************************************/
+
+#[test]
+fn dry_air_matches_c() {
+    both_unit_systems(|grid| {
+        grid.sweep(|cmp, t, rh, p| {
+            let rust = Ok(cmp.psy.get_dry_air_enthalpy(t));
+            cmp.check_rel("GetDryAirEnthalpy", &[t], rust, || unsafe {
+                GetDryAirEnthalpy(t)
+            });
+            let rust = Ok(cmp.psy.get_dry_air_density(t, p));
+            cmp.check_rel("GetDryAirDensity", &[t, p], rust, || unsafe {
+                GetDryAirDensity(t, p)
+            });
+            let rust = Ok(cmp.psy.get_dry_air_volume(t, p));
+            cmp.check_rel("GetDryAirVolume", &[t, p], rust, || unsafe {
+                GetDryAirVolume(t, p)
+            });
+
+            let rust = cmp.psy.get_hum_ratio_from_rel_hum(t, rh, p);
+            let w = match cmp.accept("GetHumRatioFromRelHum", &[t, rh, p], rust) {
+                Some(w) => w,
+                None => return,
+            };
+            let rust = cmp.psy.get_moist_air_enthalpy(t, w);
+            let h = match cmp.accept("GetMoistAirEnthalpy", &[t, w], rust) {
+                Some(h) => h,
+                None => return,
+            };
+            let rust = cmp.psy.get_t_dry_bulb_from_enthalpy_and_hum_ratio(h, w);
+            cmp.check_abs(
+                "GetTDryBulbFromEnthalpyAndHumRatio",
+                &[h, w],
+                rust,
+                || unsafe { GetTDryBulbFromEnthalpyAndHumRatio(h, w) },
+            );
+            let rust = Ok(cmp.psy.get_hum_ratio_from_enthalpy_and_t_dry_bulb(h, t));
+            cmp.check_rel(
+                "GetHumRatioFromEnthalpyAndTDryBulb",
+                &[h, t],
+                rust,
+                || unsafe { GetHumRatioFromEnthalpyAndTDryBulb(h, t) },
+            );
+        });
+    });
+}
+
+#[test]
+fn saturated_air_matches_c() {
+    both_unit_systems(|grid| {
+        grid.sweep(|cmp, t, _, p| {
+            let rust = cmp.psy.get_sat_vap_pres(t);
+            cmp.check_rel("GetSatVapPres", &[t], rust, || unsafe { GetSatVapPres(t) });
+            let rust = cmp.psy.get_sat_hum_ratio(t, p);
+            cmp.check_rel("GetSatHumRatio", &[t, p], rust, || unsafe {
+                GetSatHumRatio(t, p)
+            });
+            let rust = cmp.psy.get_sat_air_enthalpy(t, p);
+            cmp.check_rel("GetSatAirEnthalpy", &[t, p], rust, || unsafe {
+                GetSatAirEnthalpy(t, p)
+            });
+        });
+    });
+}
+
+#[test]
+fn moist_air_matches_c() {
+    both_unit_systems(|grid| {
+        grid.sweep(|cmp, t, rh, p| {
+            let rust = cmp.psy.get_hum_ratio_from_rel_hum(t, rh, p);
+            let w = match cmp.accept("GetHumRatioFromRelHum", &[t, rh, p], rust) {
+                Some(w) => w,
+                None => return,
+            };
+            // The deficit vanishes at saturation, so compare it relative to the saturation vapor pressure.
+            let scale = cmp.psy.get_sat_vap_pres(t).unwrap_or(0.0);
+            let rust = cmp.psy.get_vapor_pressure_deficit(t, w, p);
+            cmp.check(
+                "GetVaporPressureDeficit",
+                &[t, w, p],
+                rust,
+                || unsafe { GetVaporPressureDeficit(t, w, p) },
+                REL_TOLERANCE * scale,
+                REL_TOLERANCE,
+            );
+            let rust = cmp.psy.get_degree_of_saturation(t, w, p);
+            cmp.check_rel("GetDegreeOfSaturation", &[t, w, p], rust, || unsafe {
+                GetDegreeOfSaturation(t, w, p)
+            });
+            let rust = cmp.psy.get_moist_air_enthalpy(t, w);
+            cmp.check_rel("GetMoistAirEnthalpy", &[t, w], rust, || unsafe {
+                GetMoistAirEnthalpy(t, w)
+            });
+            let rust = cmp.psy.get_moist_air_density(t, w, p);
+            cmp.check_rel("GetMoistAirDensity", &[t, w, p], rust, || unsafe {
+                GetMoistAirDensity(t, w, p)
+            });
+            let rust = cmp.psy.get_moist_air_volume(t, w, p);
+            let v = cmp.check_rel("GetMoistAirVolume", &[t, w, p], rust, || unsafe {
+                GetMoistAirVolume(t, w, p)
+            });
+            if let Some(v) = v {
+                let rust = cmp
+                    .psy
+                    .get_t_dry_bulb_from_moist_air_volume_and_hum_ratio(v, w, p);
+                cmp.check_abs(
+                    "GetTDryBulbFromMoistAirVolumeAndHumRatio",
+                    &[v, w, p],
+                    rust,
+                    || unsafe { GetTDryBulbFromMoistAirVolumeAndHumRatio(v, w, p) },
+                );
+            }
+        });
+    });
+}
+
+/******************************************************************************************************
+ * Standard atmosphere
+ *****************************************************************************************************/
+
+#[test]
+fn standard_atmosphere_matches_c() {
+    both_unit_systems(|grid| {
+        let altitudes = grid.altitude.clone();
+        grid.sweep(|cmp, t, rh, p| {
+            // The standard atmosphere does not depend on humidity, only sweep it once.
+            if rh != 0.0 {
+                return;
+            }
+            for &z in &altitudes {
+                let rust = Ok(cmp.psy.get_standard_atm_pressure(z));
+                cmp.check_rel("GetStandardAtmPressure", &[z], rust, || unsafe {
+                    GetStandardAtmPressure(z)
+                });
+                let rust = Ok(cmp.psy.get_standard_atm_temperature(z));
+                cmp.check_rel("GetStandardAtmTemperature", &[z], rust, || unsafe {
+                    GetStandardAtmTemperature(z)
+                });
+                let rust = Ok(cmp.psy.get_sea_level_pressure(p, z, t));
+                cmp.check_rel("GetSeaLevelPressure", &[p, z, t], rust, || unsafe {
+                    GetSeaLevelPressure(p, z, t)
+                });
+                let rust = Ok(cmp.psy.get_station_pressure(p, z, t));
+                cmp.check_rel("GetStationPressure", &[p, z, t], rust, || unsafe {
+                    GetStationPressure(p, z, t)
+                });
+            }
+        });
+    });
+}
+
+/******************************************************************************************************
+ * All psychrometrics
+ *****************************************************************************************************/
+
+#[test]
+fn calc_psychrometrics_match_c() {
+    both_unit_systems(|grid| {
+        grid.sweep(|cmp, t, rh, p| {
+            let tol = cmp.abs_tolerance();
+            let mut out = [0.0; 7];
+
+            let rust = cmp.psy.calc_psychrometrics_from_rel_hum(t, rh, p);
+            let state = match cmp.accept("CalcPsychrometricsFromRelHum", &[t, rh, p], rust) {
+                Some(state) => state,
+                None => return,
+            };
+            unsafe {
+                let [w, tw, td, vp, h, v, mu] = &mut out;
+                CalcPsychrometricsFromRelHum(t, rh, p, w, tw, td, vp, h, v, mu);
+            }
+            let inputs = [t, rh, p];
+            let name = "CalcPsychrometricsFromRelHum";
+            cmp.check(name, &inputs, Ok(state.t_wet_bulb), || out[1], tol, 0.0);
+            cmp.check(name, &inputs, Ok(state.t_dew_point), || out[2], tol, 0.0);
+            cmp.check_rel(name, &inputs, Ok(state.hum_ratio), || out[0]);
+            cmp.check_rel(name, &inputs, Ok(state.vap_pres), || out[3]);
+            cmp.check_rel(name, &inputs, Ok(state.moist_air_enthalpy), || out[4]);
+            cmp.check_rel(name, &inputs, Ok(state.moist_air_volume), || out[5]);
+            cmp.check_rel(name, &inputs, Ok(state.degree_of_saturation), || out[6]);
+
+            let (tw, td) = (state.t_wet_bulb, state.t_dew_point);
+            let rust = cmp.psy.calc_psychrometrics_from_t_wet_bulb(t, tw, p);
+            if let Some(state) = cmp.accept("CalcPsychrometricsFromTWetBulb", &[t, tw, p], rust) {
+                unsafe {
+                    let [w, td, rh, vp, h, v, mu] = &mut out;
+                    CalcPsychrometricsFromTWetBulb(t, tw, p, w, td, rh, vp, h, v, mu);
+                }
+                let inputs = [t, tw, p];
+                let name = "CalcPsychrometricsFromTWetBulb";
+                cmp.check(name, &inputs, Ok(state.t_dew_point), || out[1], tol, 0.0);
+                cmp.check_rel(name, &inputs, Ok(state.hum_ratio), || out[0]);
+                cmp.check_rel(name, &inputs, Ok(state.rel_hum), || out[2]);
+                cmp.check_rel(name, &inputs, Ok(state.vap_pres), || out[3]);
+                cmp.check_rel(name, &inputs, Ok(state.moist_air_enthalpy), || out[4]);
+                cmp.check_rel(name, &inputs, Ok(state.moist_air_volume), || out[5]);
+                cmp.check_rel(name, &inputs, Ok(state.degree_of_saturation), || out[6]);
+            }
+
+            let rust = cmp.psy.calc_psychrometrics_from_t_dew_point(t, td, p);
+            if let Some(state) = cmp.accept("CalcPsychrometricsFromTDewPoint", &[t, td, p], rust) {
+                unsafe {
+                    let [w, tw, rh, vp, h, v, mu] = &mut out;
+                    CalcPsychrometricsFromTDewPoint(t, td, p, w, tw, rh, vp, h, v, mu);
+                }
+                let inputs = [t, td, p];
+                let name = "CalcPsychrometricsFromTDewPoint";
+                cmp.check(name, &inputs, Ok(state.t_wet_bulb), || out[1], tol, 0.0);
+                cmp.check_rel(name, &inputs, Ok(state.hum_ratio), || out[0]);
+                cmp.check_rel(name, &inputs, Ok(state.rel_hum), || out[2]);
+                cmp.check_rel(name, &inputs, Ok(state.vap_pres), || out[3]);
+                cmp.check_rel(name, &inputs, Ok(state.moist_air_enthalpy), || out[4]);
+                cmp.check_rel(name, &inputs, Ok(state.moist_air_volume), || out[5]);
+                cmp.check_rel(name, &inputs, Ok(state.degree_of_saturation), || out[6]);
+            }
+        });
+    });
+}
@@ -0,0 +1,400 @@
+/*
+ * PsychroLib (version 2.5.0) (https://github.com/psychrometrics/psychrolib).
+ * Copyright (c) 2018-2020 The PsychroLib Contributors. Licensed under the MIT License.
+*/
+
+// Test of PsychroLib in IP units for Rust.
+// Port of tests/test_psychrolib_ip.py, with the same values and tolerances.
+
+mod common;
+
+use common::{assert_abs, assert_rel};
+use psychrolib::{Psychrolib, UnitSystem};
+
+fn psy() -> Psychrolib {
+    Psychrolib::new(UnitSystem::IP)
+}
+
+// Test of helper functions
+#[test]
+fn test_get_t_rankine_from_t_fahrenheit() {
+    assert_rel(
+        psychrolib::get_t_rankine_from_t_fahrenheit(70.0),
+        529.67,
+        0.000001,
+    );
+}
+
+#[test]
+fn test_get_t_fahrenheit_from_t_rankine() {
+    assert_rel(
+        psychrolib::get_t_fahrenheit_from_t_rankine(529.67),
+        70.0,
+        0.000001,
+    );
+}
+
+/******************************************************************************************************
+ * Tests at saturation
+ *****************************************************************************************************/
+
+// Test saturation vapour pressure calculation
+// The values are tested against the values published in Table 3 of ch. 1 of the 2017 ASHRAE Handbook - Fundamentals
+// over the range [-148, +392] F
+// ASHRAE's assertion is that the formula is within 300 ppm of the true values, which is true except for the value at -76 F
+#[test]
+fn test_get_sat_vap_pres() {
+    let psy = psy();
+    assert_abs(psy.get_sat_vap_pres(-76.0).unwrap(), 0.000157, 0.00001);
+    assert_rel(psy.get_sat_vap_pres(-4.0).unwrap(), 0.014974, 0.0003);
+    assert_rel(psy.get_sat_vap_pres(23.0).unwrap(), 0.058268, 0.0003);
+    assert_rel(psy.get_sat_vap_pres(41.0).unwrap(), 0.12656, 0.0003);
+    assert_rel(psy.get_sat_vap_pres(77.0).unwrap(), 0.45973, 0.0003);
+    assert_rel(psy.get_sat_vap_pres(122.0).unwrap(), 1.79140, 0.0003);
+    assert_rel(psy.get_sat_vap_pres(212.0).unwrap(), 14.7094, 0.0003);
+    assert_rel(psy.get_sat_vap_pres(300.0).unwrap(), 67.0206, 0.0003);
+}
+
+// Test that the NR in get_t_dew_point_from_vap_pres converges.
+// This test was known problem in versions of PsychroLib <= 2.0.0
+#[test]
+fn test_get_t_dew_point_from_vap_pres_convergence() {
+    let psy = psy();
+    for t_dry_bulb in -148..392 {
+        for rel_hum in 0..10 {
+            for pressure in 0..9 {
+                psy.get_t_wet_bulb_from_rel_hum(
+                    t_dry_bulb as f64,
+                    rel_hum as f64 * 0.1,
+                    8.6 + pressure as f64,
+                )
+                .unwrap();
+            }
+        }
+    }
+}
+
+// Test saturation humidity ratio
+// The values are tested against those published in Table 2 of ch. 1 of the 2017 ASHRAE Handbook - Fundamentals
+// Agreement is not terrific - up to 2% difference with the values published in the table
+#[test]
+fn test_get_sat_hum_ratio() {
+    let psy = psy();
+    assert_rel(
+        psy.get_sat_hum_ratio(-58.0, 14.696).unwrap(),
+        0.0000243,
+        0.01,
+    );
+    assert_rel(
+        psy.get_sat_hum_ratio(-4.0, 14.696).unwrap(),
+        0.0006373,
+        0.01,
+    );
+    assert_rel(
+        psy.get_sat_hum_ratio(23.0, 14.696).unwrap(),
+        0.0024863,
+        0.005,
+    );
+    assert_rel(
+        psy.get_sat_hum_ratio(41.0, 14.696).unwrap(),
+        0.005425,
+        0.005,
+    );
+    assert_rel(
+        psy.get_sat_hum_ratio(77.0, 14.696).unwrap(),
+        0.020173,
+        0.005,
+    );
+    assert_rel(
+        psy.get_sat_hum_ratio(122.0, 14.696).unwrap(),
+        0.086863,
+        0.01,
+    );
+    assert_rel(
+        psy.get_sat_hum_ratio(185.0, 14.696).unwrap(),
+        0.838105,
+        0.02,
+    );
+}
+
+// Test enthalpy at saturation
+// The values are tested against those published in Table 2 of ch. 1 of the 2017 ASHRAE Handbook - Fundamentals
+// Agreement is rarely better than 1%, and close to 3% at -5 C
+#[test]
+fn test_get_sat_air_enthalpy() {
+    let psy = psy();
+    assert_rel(
+        psy.get_sat_air_enthalpy(-58.0, 14.696).unwrap(),
+        -13.906,
+        0.01,
+    );
+    assert_rel(
+        psy.get_sat_air_enthalpy(-4.0, 14.696).unwrap(),
+        -0.286,
+        0.01,
+    );
+    assert_rel(psy.get_sat_air_enthalpy(23.0, 14.696).unwrap(), 8.186, 0.03);
+    assert_rel(
+        psy.get_sat_air_enthalpy(41.0, 14.696).unwrap(),
+        15.699,
+        0.01,
+    );
+    assert_rel(
+        psy.get_sat_air_enthalpy(77.0, 14.696).unwrap(),
+        40.576,
+        0.01,
+    );
+    assert_rel(
+        psy.get_sat_air_enthalpy(122.0, 14.696).unwrap(),
+        126.066,
+        0.01,
+    );
+    assert_rel(
+        psy.get_sat_air_enthalpy(185.0, 14.696).unwrap(),
+        999.749,
+        0.01,
+    );
+}
+
+/******************************************************************************************************
+ * Test of primary relationships between wet bulb temperature, humidity ratio, vapour pressure, relative humidity,
+ * and dew point temperatures
+ * These relationships are identified with bold arrows in the doc's diagram
+ *****************************************************************************************************/
+
+// Test of relationships between vapour pressure and dew point temperature
+// No need to test vapour pressure calculation as it is just the saturation vapour pressure tested above
+#[test]
+fn test_vap_pres_t_dew_point() {
+    let psy = psy();
+    let vap_pres = psy.get_vap_pres_from_t_dew_point(-4.0).unwrap();
+    assert_abs(
+        psy.get_t_dew_point_from_vap_pres(59.0, vap_pres).unwrap(),
+        -4.0,
+        0.001,
+    );
+    let vap_pres = psy.get_vap_pres_from_t_dew_point(41.0).unwrap();
+    assert_abs(
+        psy.get_t_dew_point_from_vap_pres(59.0, vap_pres).unwrap(),
+        41.0,
+        0.001,
+    );
+    let vap_pres = psy.get_vap_pres_from_t_dew_point(122.0).unwrap();
+    assert_abs(
+        psy.get_t_dew_point_from_vap_pres(140.0, vap_pres).unwrap(),
+        122.0,
+        0.001,
+    );
+}
+
+// Test of relationships between humidity ratio and vapour pressure
+// Humidity ratio values to test against are calculated with Excel
+#[test]
+fn test_hum_ratio_vap_pres() {
+    let psy = psy();
+    let hum_ratio = psy.get_hum_ratio_from_vap_pres(0.45973, 14.175).unwrap(); // conditions at 77 F, std atm pressure at 1000 ft
+    assert_rel(hum_ratio, 0.0208473311024865, 0.000001);
+    let vap_pres = psy.get_vap_pres_from_hum_ratio(hum_ratio, 14.175).unwrap();
+    assert_abs(vap_pres, 0.45973, 0.00001);
+}
+
+// Test of relationships between vapour pressure and relative humidity
+#[test]
+fn test_vap_pres_rel_hum() {
+    let psy = psy();
+    let vap_pres = psy.get_vap_pres_from_rel_hum(77.0, 0.8).unwrap();
+    assert_rel(vap_pres, 0.45973 * 0.8, 0.0003);
+    let rel_hum = psy.get_rel_hum_from_vap_pres(77.0, vap_pres).unwrap();
+    assert_rel(rel_hum, 0.8, 0.0003);
+}
+
+// Test of relationships between humidity ratio and wet bulb temperature
+// The formulae are tested for two conditions, one above freezing and the other below
+// Humidity ratio values to test against are calculated with Excel
+#[test]
+fn test_hum_ratio_t_wet_bulb() {
+    let psy = psy();
+    // Above freezing
+    let hum_ratio = psy
+        .get_hum_ratio_from_t_wet_bulb(86.0, 77.0, 14.175)
+        .unwrap();
+    assert_rel(hum_ratio, 0.0187193288418892, 0.0003);
+    let t_wet_bulb = psy
+        .get_t_wet_bulb_from_hum_ratio(86.0, hum_ratio, 14.175)
+        .unwrap();
+    assert_abs(t_wet_bulb, 77.0, 0.001);
+    // Below freezing
+    let hum_ratio = psy
+        .get_hum_ratio_from_t_wet_bulb(30.2, 23.0, 14.175)
+        .unwrap();
+    assert_rel(hum_ratio, 0.00114657481090184, 0.0003);
+    let t_wet_bulb = psy
+        .get_t_wet_bulb_from_hum_ratio(30.2, hum_ratio, 14.1751)
+        .unwrap();
+    assert_abs(t_wet_bulb, 23.0, 0.001);
+    // Low HumRatio -- this should evaluate true as we clamp the HumRation to 1e-07.
+    assert_eq!(
+        psy.get_t_wet_bulb_from_hum_ratio(25.0, 1e-09, 95461.0)
+            .unwrap(),
+        psy.get_t_wet_bulb_from_hum_ratio(25.0, 1e-07, 95461.0)
+            .unwrap()
+    );
+}
+
+/******************************************************************************************************
+ * Dry air calculations
+ *****************************************************************************************************/
+
+// Values are compared against values found in Table 2 of ch. 1 of the ASHRAE Handbook - Fundamentals
+// Note: the accuracy of the formula is not better than 0.1%, apparently
+#[test]
+fn test_dry_air() {
+    let psy = psy();
+    assert_rel(psy.get_dry_air_enthalpy(77.0), 18.498, 0.001);
+    assert_rel(psy.get_dry_air_volume(77.0, 14.696), 13.5251, 0.001);
+    assert_rel(psy.get_dry_air_density(77.0, 14.696), 1.0 / 13.5251, 0.001);
+    assert_abs(
+        psy.get_t_dry_bulb_from_enthalpy_and_hum_ratio(42.6168, 0.02)
+            .unwrap(),
+        85.97,
+        0.05,
+    );
+    assert_rel(
+        psy.get_hum_ratio_from_enthalpy_and_t_dry_bulb(42.6168, 86.0),
+        0.02,
+        0.001,
+    );
+}
+
+/******************************************************************************************************
+ * Moist air calculations
+ *****************************************************************************************************/
+
+// Values are compared against values calculated with Excel
+#[test]
+fn test_moist_air() {
+    let psy = psy();
+    assert_rel(
+        psy.get_moist_air_enthalpy(86.0, 0.02).unwrap(),
+        42.6168,
+        0.0003,
+    );
+    assert_rel(
+        psy.get_moist_air_volume(86.0, 0.02, 14.175).unwrap(),
+        14.7205749002918,
+        0.0003,
+    );
+    assert_rel(
+        psy.get_moist_air_density(86.0, 0.02, 14.175).unwrap(),
+        0.0692907720594378,
+        0.0003,
+    );
+}
+
+#[test]
+fn test_get_t_dry_bulb_from_moist_air_volume_and_hum_ratio() {
+    let psy = psy();
+    let t_dry_bulb = psy
+        .get_t_dry_bulb_from_moist_air_volume_and_hum_ratio(14.7205749002918, 0.02, 14.175)
+        .unwrap();
+    assert_rel(t_dry_bulb, 86.0, 0.0003);
+}
+
+/******************************************************************************************************
+ * Test standard atmosphere
+ *****************************************************************************************************/
+
+// The functions are tested against Table 1 of ch. 1 of the 2017 ASHRAE Handbook - Fundamentals
+#[test]
+fn test_get_standard_atm_pressure() {
+    let psy = psy();
+    assert_abs(psy.get_standard_atm_pressure(-1000.0), 15.236, 1.0);
+    assert_abs(psy.get_standard_atm_pressure(0.0), 14.696, 1.0);
+    assert_abs(psy.get_standard_atm_pressure(1000.0), 14.175, 1.0);
+    assert_abs(psy.get_standard_atm_pressure(3000.0), 13.173, 1.0);
+    assert_abs(psy.get_standard_atm_pressure(10000.0), 10.108, 1.0);
+    assert_abs(psy.get_standard_atm_pressure(30000.0), 4.371, 1.0);
+}
+
+#[test]
+fn test_get_standard_atm_temperature() {
+    let psy = psy();
+    assert_abs(psy.get_standard_atm_temperature(-1000.0), 62.6, 0.1);
+    assert_abs(psy.get_standard_atm_temperature(0.0), 59.0, 0.1);
+    assert_abs(psy.get_standard_atm_temperature(1000.0), 55.4, 0.1);
+    assert_abs(psy.get_standard_atm_temperature(3000.0), 48.3, 0.1);
+    assert_abs(psy.get_standard_atm_temperature(10000.0), 23.4, 0.1);
+    assert_abs(psy.get_standard_atm_temperature(30000.0), -47.8, 0.2); // Doesn't work with abs = 0.1
+}
+
+/******************************************************************************************************
+ * Test sea level pressure conversions
+ *****************************************************************************************************/
+
+// Test sea level pressure calculation against https://keisan.casio.com/exec/system/1224575267,
+// converted to IP
+#[test]
+fn test_sea_level_station_pressure() {
+    let psy = psy();
+    let sea_level_pressure = psy.get_sea_level_pressure(14.681662559, 344.488, 62.942);
+    assert_abs(sea_level_pressure, 14.8640475, 0.0001);
+    assert_abs(
+        psy.get_station_pressure(sea_level_pressure, 344.488, 62.942),
+        14.681662559,
+        0.0001,
+    );
+}
+
+/******************************************************************************************************
+ * Test conversion between humidity types
+ *****************************************************************************************************/
+
+#[test]
+fn test_get_specific_hum_from_hum_ratio() {
+    assert_rel(
+        psy().get_specific_hum_from_hum_ratio(0.006).unwrap(),
+        0.00596421471,
+        0.01,
+    );
+}
+
+#[test]
+fn test_get_hum_ratio_from_specific_hum() {
+    assert_rel(
+        psy()
+            .get_hum_ratio_from_specific_hum(0.00596421471)
+            .unwrap(),
+        0.006,
+        0.01,
+    );
+}
+
+/******************************************************************************************************
+ * Test against Example 1 of ch. 1 of the 2017 ASHRAE Handbook - Fundamentals
+ *****************************************************************************************************/
+
+#[test]
+fn test_all_psychrometrics() {
+    let psy = psy();
+    // This is example 1. The values are provided in the text of the Handbook
+    let state = psy
+        .calc_psychrometrics_from_t_wet_bulb(100.0, 65.0, 14.696)
+        .unwrap();
+    assert_abs(state.hum_ratio, 0.00523, 0.001);
+    assert_abs(state.t_dew_point, 40.0, 1.0); // not great agreement
+    assert_abs(state.rel_hum, 0.13, 0.01);
+    assert_abs(state.moist_air_enthalpy, 29.80, 0.1);
+    assert_rel(state.moist_air_volume, 14.22, 0.01);
+
+    // Reverse calculation: recalculate wet bulb temperature from dew point temperature
+    let from_t_dew_point = psy
+        .calc_psychrometrics_from_t_dew_point(100.0, state.t_dew_point, 14.696)
+        .unwrap();
+    assert_abs(from_t_dew_point.t_wet_bulb, 65.0, 0.1);
+
+    // Reverse calculation: recalculate wet bulb temperature from relative humidity
+    let from_rel_hum = psy
+        .calc_psychrometrics_from_rel_hum(100.0, state.rel_hum, 14.696)
+        .unwrap();
+    assert_abs(from_rel_hum.t_wet_bulb, 65.0, 0.1);
+}
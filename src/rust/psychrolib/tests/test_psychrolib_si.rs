@@ -0,0 +1,415 @@
+/*
+ * PsychroLib (version 2.5.0) (https://github.com/psychrometrics/psychrolib).
+ * Copyright (c) 2018-2020 The PsychroLib Contributors. Licensed under the MIT License.
+*/
+
+// Test of PsychroLib in SI units for Rust.
+// Port of tests/test_psychrolib_si.py, with the same values and tolerances.
+
+mod common;
+
+use common::{assert_abs, assert_rel};
+use psychrolib::{Psychrolib, UnitSystem};
+
+fn psy() -> Psychrolib {
+    Psychrolib::new(UnitSystem::SI)
+}
+
+// Test of helper functions
+#[test]
+fn test_get_t_kelvin_from_t_celsius() {
+    assert_rel(
+        psychrolib::get_t_kelvin_from_t_celsius(20.0),
+        293.15,
+        0.000001,
+    );
+}
+
+#[test]
+fn test_get_t_celsius_from_t_kelvin() {
+    assert_rel(
+        psychrolib::get_t_celsius_from_t_kelvin(293.15),
+        20.0,
+        0.000001,
+    );
+}
+
+/******************************************************************************************************
+ * Tests at saturation
+ *****************************************************************************************************/
+
+// Test saturation vapour pressure calculation
+// The values are tested against the values published in Table 3 of ch. 1 of the 2017 ASHRAE Handbook - Fundamentals
+// over the range [-100, +200] C
+// ASHRAE's assertion is that the formula is within 300 ppm of the true values, which is true except for the value at -60 C
+#[test]
+fn test_get_sat_vap_pres() {
+    let psy = psy();
+    assert_abs(psy.get_sat_vap_pres(-60.0).unwrap(), 1.08, 0.01);
+    assert_rel(psy.get_sat_vap_pres(-20.0).unwrap(), 103.24, 0.0003);
+    assert_rel(psy.get_sat_vap_pres(-5.0).unwrap(), 401.74, 0.0003);
+    assert_rel(psy.get_sat_vap_pres(5.0).unwrap(), 872.6, 0.0003);
+    assert_rel(psy.get_sat_vap_pres(25.0).unwrap(), 3169.7, 0.0003);
+    assert_rel(psy.get_sat_vap_pres(50.0).unwrap(), 12351.3, 0.0003);
+    assert_rel(psy.get_sat_vap_pres(100.0).unwrap(), 101418.0, 0.0003);
+    assert_rel(psy.get_sat_vap_pres(150.0).unwrap(), 476101.4, 0.0003);
+}
+
+// Test saturation humidity ratio
+// The values are tested against those published in Table 2 of ch. 1 of the 2017 ASHRAE Handbook - Fundamentals
+// Agreement is not terrific - up to 2% difference with the values published in the table
+#[test]
+fn test_get_sat_hum_ratio() {
+    let psy = psy();
+    assert_rel(
+        psy.get_sat_hum_ratio(-50.0, 101325.0).unwrap(),
+        0.0000243,
+        0.01,
+    );
+    assert_rel(
+        psy.get_sat_hum_ratio(-20.0, 101325.0).unwrap(),
+        0.0006373,
+        0.01,
+    );
+    assert_rel(
+        psy.get_sat_hum_ratio(-5.0, 101325.0).unwrap(),
+        0.0024863,
+        0.005,
+    );
+    assert_rel(
+        psy.get_sat_hum_ratio(5.0, 101325.0).unwrap(),
+        0.005425,
+        0.005,
+    );
+    assert_rel(
+        psy.get_sat_hum_ratio(25.0, 101325.0).unwrap(),
+        0.020173,
+        0.005,
+    );
+    assert_rel(
+        psy.get_sat_hum_ratio(50.0, 101325.0).unwrap(),
+        0.086863,
+        0.01,
+    );
+    assert_rel(
+        psy.get_sat_hum_ratio(85.0, 101325.0).unwrap(),
+        0.838105,
+        0.02,
+    );
+}
+
+// Test enthalpy at saturation
+// The values are tested against those published in Table 2 of ch. 1 of the 2017 ASHRAE Handbook - Fundamentals
+// Agreement is rarely better than 1%, and close to 3% at -5 C
+#[test]
+fn test_get_sat_air_enthalpy() {
+    let psy = psy();
+    assert_rel(
+        psy.get_sat_air_enthalpy(-50.0, 101325.0).unwrap(),
+        -50222.0,
+        0.01,
+    );
+    assert_rel(
+        psy.get_sat_air_enthalpy(-20.0, 101325.0).unwrap(),
+        -18542.0,
+        0.01,
+    );
+    assert_rel(
+        psy.get_sat_air_enthalpy(-5.0, 101325.0).unwrap(),
+        1164.0,
+        0.03,
+    );
+    assert_rel(
+        psy.get_sat_air_enthalpy(5.0, 101325.0).unwrap(),
+        18639.0,
+        0.01,
+    );
+    assert_rel(
+        psy.get_sat_air_enthalpy(25.0, 101325.0).unwrap(),
+        76504.0,
+        0.01,
+    );
+    assert_rel(
+        psy.get_sat_air_enthalpy(50.0, 101325.0).unwrap(),
+        275353.0,
+        0.01,
+    );
+    assert_rel(
+        psy.get_sat_air_enthalpy(85.0, 101325.0).unwrap(),
+        2307539.0,
+        0.01,
+    );
+}
+
+/******************************************************************************************************
+ * Test of primary relationships between wet bulb temperature, humidity ratio, vapour pressure, relative humidity,
+ * and dew point temperatures
+ * These relationships are identified with bold arrows in the doc's diagram
+ *****************************************************************************************************/
+
+// Test of relationships between vapour pressure and dew point temperature
+// No need to test vapour pressure calculation as it is just the saturation vapour pressure tested above
+#[test]
+fn test_vap_pres_t_dew_point() {
+    let psy = psy();
+    let vap_pres = psy.get_vap_pres_from_t_dew_point(-20.0).unwrap();
+    assert_abs(
+        psy.get_t_dew_point_from_vap_pres(15.0, vap_pres).unwrap(),
+        -20.0,
+        0.001,
+    );
+    let vap_pres = psy.get_vap_pres_from_t_dew_point(5.0).unwrap();
+    assert_abs(
+        psy.get_t_dew_point_from_vap_pres(15.0, vap_pres).unwrap(),
+        5.0,
+        0.001,
+    );
+    let vap_pres = psy.get_vap_pres_from_t_dew_point(50.0).unwrap();
+    assert_abs(
+        psy.get_t_dew_point_from_vap_pres(60.0, vap_pres).unwrap(),
+        50.0,
+        0.001,
+    );
+}
+
+// Test of relationships between wet bulb temperature and relative humidity
+// This test was known to cause a convergence issue in GetTDewPointFromVapPres
+// in versions of PsychroLib <= 2.0.0
+#[test]
+fn test_t_wet_bulb_rel_hum() {
+    let psy = psy();
+    let t_wet_bulb = psy
+        .get_t_wet_bulb_from_rel_hum(7.0, 0.61, 100000.0)
+        .unwrap();
+    assert_rel(t_wet_bulb, 3.92667433781955, 0.001);
+}
+
+// Test that the NR in get_t_dew_point_from_vap_pres converges.
+// This test was known problem in versions of PsychroLib <= 2.0.0
+#[test]
+fn test_get_t_dew_point_from_vap_pres_convergence() {
+    let psy = psy();
+    for t_dry_bulb in -100..200 {
+        for rel_hum in 0..10 {
+            for pressure in (60000..120000).step_by(10000) {
+                psy.get_t_wet_bulb_from_rel_hum(
+                    t_dry_bulb as f64,
+                    rel_hum as f64 * 0.1,
+                    pressure as f64,
+                )
+                .unwrap();
+            }
+        }
+    }
+}
+
+// Test of relationships between humidity ratio and vapour pressure
+// Humidity ratio values to test against are calculated with Excel
+#[test]
+fn test_hum_ratio_vap_pres() {
+    let psy = psy();
+    let hum_ratio = psy.get_hum_ratio_from_vap_pres(3169.7, 95461.0).unwrap(); // conditions at 25 C, std atm pressure at 500 m
+    assert_rel(hum_ratio, 0.0213603998047487, 0.000001);
+    let vap_pres = psy.get_vap_pres_from_hum_ratio(hum_ratio, 95461.0).unwrap();
+    assert_abs(vap_pres, 3169.7, 0.0001);
+}
+
+// Test of relationships between vapour pressure and relative humidity
+#[test]
+fn test_vap_pres_rel_hum() {
+    let psy = psy();
+    let vap_pres = psy.get_vap_pres_from_rel_hum(25.0, 0.8).unwrap();
+    assert_rel(vap_pres, 3169.7 * 0.8, 0.0003);
+    let rel_hum = psy.get_rel_hum_from_vap_pres(25.0, vap_pres).unwrap();
+    assert_rel(rel_hum, 0.8, 0.0003);
+}
+
+// Test of relationships between humidity ratio and wet bulb temperature
+// The formulae are tested for two conditions, one above freezing and the other below
+// Humidity ratio values to test against are calculated with Excel
+#[test]
+fn test_hum_ratio_t_wet_bulb() {
+    let psy = psy();
+    // Above freezing
+    let hum_ratio = psy
+        .get_hum_ratio_from_t_wet_bulb(30.0, 25.0, 95461.0)
+        .unwrap();
+    assert_rel(hum_ratio, 0.0192281274241096, 0.0003);
+    let t_wet_bulb = psy
+        .get_t_wet_bulb_from_hum_ratio(30.0, hum_ratio, 95461.0)
+        .unwrap();
+    assert_abs(t_wet_bulb, 25.0, 0.001);
+    // Below freezing
+    let hum_ratio = psy
+        .get_hum_ratio_from_t_wet_bulb(-1.0, -5.0, 95461.0)
+        .unwrap();
+    assert_rel(hum_ratio, 0.00120399819933844, 0.0003);
+    let t_wet_bulb = psy
+        .get_t_wet_bulb_from_hum_ratio(-1.0, hum_ratio, 95461.0)
+        .unwrap();
+    assert_abs(t_wet_bulb, -5.0, 0.001);
+    // Low HumRatio -- this should evaluate true as we clamp the HumRation to 1e-07.
+    assert_eq!(
+        psy.get_t_wet_bulb_from_hum_ratio(-5.0, 1e-09, 95461.0)
+            .unwrap(),
+        psy.get_t_wet_bulb_from_hum_ratio(-5.0, 1e-07, 95461.0)
+            .unwrap()
+    );
+}
+
+/******************************************************************************************************
+ * Dry air calculations
+ *****************************************************************************************************/
+
+// Values are compared against values found in Table 2 of ch. 1 of the ASHRAE Handbook - Fundamentals
+// Note: the accuracy of the formula is not better than 0.1%, apparently
+#[test]
+fn test_dry_air() {
+    let psy = psy();
+    assert_rel(psy.get_dry_air_enthalpy(25.0), 25148.0, 0.0003);
+    assert_rel(psy.get_dry_air_volume(25.0, 101325.0), 0.8443, 0.001);
+    assert_rel(psy.get_dry_air_density(25.0, 101325.0), 1.0 / 0.8443, 0.001);
+    assert_abs(
+        psy.get_t_dry_bulb_from_enthalpy_and_hum_ratio(81316.0, 0.02)
+            .unwrap(),
+        30.0,
+        0.001,
+    );
+    assert_rel(
+        psy.get_hum_ratio_from_enthalpy_and_t_dry_bulb(81316.0, 30.0),
+        0.02,
+        0.001,
+    );
+}
+
+/******************************************************************************************************
+ * Moist air calculations
+ *****************************************************************************************************/
+
+// Values are compared against values calculated with Excel
+#[test]
+fn test_moist_air() {
+    let psy = psy();
+    assert_rel(
+        psy.get_moist_air_enthalpy(30.0, 0.02).unwrap(),
+        81316.0,
+        0.0003,
+    );
+    assert_rel(
+        psy.get_moist_air_volume(30.0, 0.02, 95461.0).unwrap(),
+        0.940855374352943,
+        0.0003,
+    );
+    assert_rel(
+        psy.get_moist_air_density(30.0, 0.02, 95461.0).unwrap(),
+        1.08411986348219,
+        0.0003,
+    );
+}
+
+#[test]
+fn test_get_t_dry_bulb_from_moist_air_volume_and_hum_ratio() {
+    let psy = psy();
+    let t_dry_bulb = psy
+        .get_t_dry_bulb_from_moist_air_volume_and_hum_ratio(0.940855374352943, 0.02, 95461.0)
+        .unwrap();
+    assert_rel(t_dry_bulb, 30.0, 0.0003);
+}
+
+/******************************************************************************************************
+ * Test standard atmosphere
+ *****************************************************************************************************/
+
+// The functions are tested against Table 1 of ch. 1 of the 2017 ASHRAE Handbook - Fundamentals
+#[test]
+fn test_get_standard_atm_pressure() {
+    let psy = psy();
+    assert_abs(psy.get_standard_atm_pressure(-500.0), 107478.0, 1.0);
+    assert_abs(psy.get_standard_atm_pressure(0.0), 101325.0, 1.0);
+    assert_abs(psy.get_standard_atm_pressure(500.0), 95461.0, 1.0);
+    assert_abs(psy.get_standard_atm_pressure(1000.0), 89875.0, 1.0);
+    assert_abs(psy.get_standard_atm_pressure(4000.0), 61640.0, 1.0);
+    assert_abs(psy.get_standard_atm_pressure(10000.0), 26436.0, 1.0);
+}
+
+#[test]
+fn test_get_standard_atm_temperature() {
+    let psy = psy();
+    assert_abs(psy.get_standard_atm_temperature(-500.0), 18.2, 0.1);
+    assert_abs(psy.get_standard_atm_temperature(0.0), 15.0, 0.1);
+    assert_abs(psy.get_standard_atm_temperature(500.0), 11.8, 0.1);
+    assert_abs(psy.get_standard_atm_temperature(1000.0), 8.5, 0.1);
+    assert_abs(psy.get_standard_atm_temperature(4000.0), -11.0, 0.1);
+    assert_abs(psy.get_standard_atm_temperature(10000.0), -50.0, 0.1);
+}
+
+/******************************************************************************************************
+ * Test sea level pressure conversions
+ *****************************************************************************************************/
+
+// Test sea level pressure calculation against https://keisan.casio.com/exec/system/1224575267
+#[test]
+fn test_sea_level_station_pressure() {
+    let psy = psy();
+    let sea_level_pressure = psy.get_sea_level_pressure(101226.5, 105.0, 17.19);
+    assert_abs(sea_level_pressure, 102484.0, 1.0);
+    assert_abs(
+        psy.get_station_pressure(sea_level_pressure, 105.0, 17.19),
+        101226.5,
+        1.0,
+    );
+}
+
+/******************************************************************************************************
+ * Test conversion between humidity types
+ *****************************************************************************************************/
+
+#[test]
+fn test_get_specific_hum_from_hum_ratio() {
+    assert_rel(
+        psy().get_specific_hum_from_hum_ratio(0.006).unwrap(),
+        0.00596421471,
+        0.01,
+    );
+}
+
+#[test]
+fn test_get_hum_ratio_from_specific_hum() {
+    assert_rel(
+        psy()
+            .get_hum_ratio_from_specific_hum(0.00596421471)
+            .unwrap(),
+        0.006,
+        0.01,
+    );
+}
+
+/******************************************************************************************************
+ * Test against Example 1 of ch. 1 of the 2017 ASHRAE Handbook - Fundamentals
+ *****************************************************************************************************/
+
+#[test]
+fn test_all_psychrometrics() {
+    let psy = psy();
+    // This is example 1. The values are provided in the text of the Handbook
+    let state = psy
+        .calc_psychrometrics_from_t_wet_bulb(40.0, 20.0, 101325.0)
+        .unwrap();
+    assert_abs(state.hum_ratio, 0.0065, 0.0001);
+    assert_abs(state.t_dew_point, 7.0, 0.5); // not great agreement
+    assert_abs(state.rel_hum, 0.14, 0.01);
+    assert_abs(state.moist_air_enthalpy, 56700.0, 100.0);
+    assert_rel(state.moist_air_volume, 0.896, 0.01);
+
+    // Reverse calculation: recalculate wet bulb temperature from dew point temperature
+    let from_t_dew_point = psy
+        .calc_psychrometrics_from_t_dew_point(40.0, state.t_dew_point, 101325.0)
+        .unwrap();
+    assert_abs(from_t_dew_point.t_wet_bulb, 20.0, 0.1);
+
+    // Reverse calculation: recalculate wet bulb temperature from relative humidity
+    let from_rel_hum = psy
+        .calc_psychrometrics_from_rel_hum(40.0, state.rel_hum, 101325.0)
+        .unwrap();
+    assert_abs(from_rel_hum.t_wet_bulb, 20.0, 0.1);
+}